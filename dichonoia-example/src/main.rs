@@ -1,6 +1,7 @@
-use dichonoia::gateway::GatewayClient;
+use dichonoia::gateway::{GatewayClient, GatewayError};
 use dichonoia::http::HttpClient;
 use dichonoia_models::gateway::GatewayIntents;
+use futures_util::StreamExt;
 use std::error::Error;
 
 #[tokio::main]
@@ -9,11 +10,16 @@ async fn main() -> Result<(), Box<dyn Error>> {
     let _http = HttpClient::new(&token);
 
     let intents = GatewayIntents::GUILDS;
-    let mut gateway = GatewayClient::connect(&token, intents).await?;
-    println!("Connected to gateway");
-
-    loop {
-        let payload = gateway.read_payload().await?;
-        println!("Payload: {payload:#?}");
+    let mut client = GatewayClient::connect(&token, intents).await?;
+    let mut events = client.events();
+    while let Some(event) = events.next().await {
+        match event {
+            Ok(event) => println!("Event: {event:#?}"),
+            // An event that could not be deserialized does not affect the connection.
+            Err(GatewayError::Event(err)) => eprintln!("Invalid event: {err}"),
+            Err(err) => return Err(err.into()),
+        }
     }
+
+    Ok(())
 }
@@ -0,0 +1,530 @@
+//! URLs of images on Discord's CDN.
+//!
+//! Each endpoint only serves some [formats](ImageFormat), which are checked when building
+//! its URL. GIF is only available for [animated](ImageHash::is_animated) hashes.
+//!
+//! <https://discord.com/developers/docs/reference#image-formatting>
+
+use core::fmt;
+use std::fmt::{Display, Write};
+
+use crate::{
+    id::{EmojiId, GuildId, RoleId, StickerId, UserId},
+    image_hash::ImageHash,
+    sticker::StickerFormatType,
+    user::discriminator::Discriminator,
+};
+
+/// Base URL of Discord's CDN.
+pub const CDN_URL: &str = "https://cdn.discordapp.com";
+
+/// Base URL that GIF stickers are served from.
+const MEDIA_URL: &str = "https://media.discordapp.net";
+
+/// Image Formats
+///
+/// <https://discord.com/developers/docs/reference#image-formatting-image-formats>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ImageFormat {
+    Jpeg,
+    Png,
+    WebP,
+    Gif,
+    Avif,
+}
+
+impl ImageFormat {
+    /// File extension of the format, as used in URLs.
+    #[must_use]
+    pub const fn extension(self) -> &'static str {
+        match self {
+            Self::Jpeg => "jpg",
+            Self::Png => "png",
+            Self::WebP => "webp",
+            Self::Gif => "gif",
+            Self::Avif => "avif",
+        }
+    }
+}
+
+impl Display for ImageFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.extension())
+    }
+}
+
+/// Size of a requested image, a power of 2 between 16 and 4096.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ImageSize(u16);
+
+impl ImageSize {
+    /// Returns `None` if `size` is not a power of 2 between 16 and 4096.
+    #[must_use]
+    pub const fn new(size: u16) -> Option<Self> {
+        if size.is_power_of_two() && size >= 16 && size <= 4096 {
+            Some(Self(size))
+        } else {
+            None
+        }
+    }
+
+    #[must_use]
+    pub const fn get(self) -> u16 {
+        self.0
+    }
+}
+
+/// Error returned when building the URL of an image in a format its endpoint does not
+/// serve.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CdnError {
+    /// The endpoint does not serve images in this format.
+    UnsupportedFormat(ImageFormat),
+    /// GIF was requested, but the image is not animated.
+    NotAnimated,
+}
+
+impl Display for CdnError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnsupportedFormat(format) => {
+                write!(f, "images of this kind are not available as {format}")
+            }
+            Self::NotAnimated => f.write_str("GIF was requested for an image that is not animated"),
+        }
+    }
+}
+
+impl std::error::Error for CdnError {}
+
+/// Formats served by the endpoints of animatable images.
+const ANIMATABLE: &[ImageFormat] = &[
+    ImageFormat::Png,
+    ImageFormat::Jpeg,
+    ImageFormat::WebP,
+    ImageFormat::Gif,
+];
+
+/// Formats served by the endpoints of static images.
+const STATIC: &[ImageFormat] = &[ImageFormat::Png, ImageFormat::Jpeg, ImageFormat::WebP];
+
+/// Formats served by the custom emoji endpoint.
+const EMOJI: &[ImageFormat] = &[
+    ImageFormat::Png,
+    ImageFormat::Jpeg,
+    ImageFormat::WebP,
+    ImageFormat::Gif,
+    ImageFormat::Avif,
+];
+
+/// URL of a custom emoji.
+///
+/// # Errors
+///
+/// Returns an error if `format` is not PNG, JPEG, WebP, GIF or AVIF.
+pub fn emoji(
+    emoji_id: EmojiId,
+    format: ImageFormat,
+    size: Option<ImageSize>,
+) -> Result<String, CdnError> {
+    check_format(format, EMOJI)?;
+    Ok(url(
+        format_args!("emojis/{}", emoji_id.get()),
+        format,
+        size,
+        false,
+    ))
+}
+
+/// URL of the icon of a guild.
+///
+/// # Errors
+///
+/// Returns an error if `format` is not PNG, JPEG, WebP or GIF, or is GIF while `hash` is
+/// not animated.
+pub fn guild_icon(
+    guild_id: GuildId,
+    hash: ImageHash,
+    format: ImageFormat,
+    size: Option<ImageSize>,
+) -> Result<String, CdnError> {
+    image(
+        format_args!("icons/{}/{hash}", guild_id.get()),
+        hash,
+        format,
+        size,
+        ANIMATABLE,
+    )
+}
+
+/// URL of the splash of a guild.
+///
+/// # Errors
+///
+/// Returns an error if `format` is not PNG, JPEG or WebP.
+pub fn guild_splash(
+    guild_id: GuildId,
+    hash: ImageHash,
+    format: ImageFormat,
+    size: Option<ImageSize>,
+) -> Result<String, CdnError> {
+    image(
+        format_args!("splashes/{}/{hash}", guild_id.get()),
+        hash,
+        format,
+        size,
+        STATIC,
+    )
+}
+
+/// URL of the discovery splash of a guild.
+///
+/// # Errors
+///
+/// Returns an error if `format` is not PNG, JPEG or WebP.
+pub fn guild_discovery_splash(
+    guild_id: GuildId,
+    hash: ImageHash,
+    format: ImageFormat,
+    size: Option<ImageSize>,
+) -> Result<String, CdnError> {
+    image(
+        format_args!("discovery-splashes/{}/{hash}", guild_id.get()),
+        hash,
+        format,
+        size,
+        STATIC,
+    )
+}
+
+/// URL of the banner of a guild.
+///
+/// # Errors
+///
+/// Returns an error if `format` is not PNG, JPEG, WebP or GIF, or is GIF while `hash` is
+/// not animated.
+pub fn guild_banner(
+    guild_id: GuildId,
+    hash: ImageHash,
+    format: ImageFormat,
+    size: Option<ImageSize>,
+) -> Result<String, CdnError> {
+    image(
+        format_args!("banners/{}/{hash}", guild_id.get()),
+        hash,
+        format,
+        size,
+        ANIMATABLE,
+    )
+}
+
+/// URL of the banner of a user.
+///
+/// # Errors
+///
+/// Returns an error if `format` is not PNG, JPEG, WebP or GIF, or is GIF while `hash` is
+/// not animated.
+pub fn user_banner(
+    user_id: UserId,
+    hash: ImageHash,
+    format: ImageFormat,
+    size: Option<ImageSize>,
+) -> Result<String, CdnError> {
+    image(
+        format_args!("banners/{}/{hash}", user_id.get()),
+        hash,
+        format,
+        size,
+        ANIMATABLE,
+    )
+}
+
+/// URL of the default avatar of a user, shown when they have not set an avatar.
+///
+/// Users who migrated to unique usernames have no `discriminator`, and their default
+/// avatar depends on their ID instead.
+#[must_use]
+pub fn default_avatar(user_id: UserId, discriminator: Option<Discriminator>) -> String {
+    let index = discriminator.map_or_else(
+        || (user_id.get() >> 22) % 6,
+        |discriminator| u64::from(discriminator.0.get() % 5),
+    );
+    url(
+        format_args!("embed/avatars/{index}"),
+        ImageFormat::Png,
+        None,
+        false,
+    )
+}
+
+/// URL of the avatar of a user.
+///
+/// # Errors
+///
+/// Returns an error if `format` is not PNG, JPEG, WebP or GIF, or is GIF while `hash` is
+/// not animated.
+pub fn avatar(
+    user_id: UserId,
+    hash: ImageHash,
+    format: ImageFormat,
+    size: Option<ImageSize>,
+) -> Result<String, CdnError> {
+    image(
+        format_args!("avatars/{}/{hash}", user_id.get()),
+        hash,
+        format,
+        size,
+        ANIMATABLE,
+    )
+}
+
+/// URL of the guild-specific avatar of a member.
+///
+/// # Errors
+///
+/// Returns an error if `format` is not PNG, JPEG, WebP or GIF, or is GIF while `hash` is
+/// not animated.
+pub fn member_avatar(
+    guild_id: GuildId,
+    user_id: UserId,
+    hash: ImageHash,
+    format: ImageFormat,
+    size: Option<ImageSize>,
+) -> Result<String, CdnError> {
+    image(
+        format_args!(
+            "guilds/{}/users/{}/avatars/{hash}",
+            guild_id.get(),
+            user_id.get()
+        ),
+        hash,
+        format,
+        size,
+        ANIMATABLE,
+    )
+}
+
+/// URL of an avatar decoration, which is always a PNG.
+#[must_use]
+pub fn avatar_decoration(asset: ImageHash, size: Option<ImageSize>) -> String {
+    url(
+        format_args!("avatar-decoration-presets/{asset}"),
+        ImageFormat::Png,
+        size,
+        false,
+    )
+}
+
+/// URL of a sticker, in the format it was uploaded as.
+///
+/// Lottie stickers are JSON files, and GIF stickers are served from a different host.
+/// Returns `None` for a [format](StickerFormatType::Unknown) this library does not know
+/// the URL of.
+#[must_use]
+pub fn sticker(sticker_id: StickerId, format_type: StickerFormatType) -> Option<String> {
+    let id = sticker_id.get();
+    Some(match format_type {
+        StickerFormatType::Png | StickerFormatType::Apng => format!("{CDN_URL}/stickers/{id}.png"),
+        StickerFormatType::Lottie => format!("{CDN_URL}/stickers/{id}.json"),
+        StickerFormatType::Gif => format!("{MEDIA_URL}/stickers/{id}.gif"),
+        StickerFormatType::Unknown(_) => return None,
+    })
+}
+
+/// URL of the icon of a role.
+///
+/// # Errors
+///
+/// Returns an error if `format` is not PNG, JPEG or WebP.
+pub fn role_icon(
+    role_id: RoleId,
+    hash: ImageHash,
+    format: ImageFormat,
+    size: Option<ImageSize>,
+) -> Result<String, CdnError> {
+    image(
+        format_args!("role-icons/{}/{hash}", role_id.get()),
+        hash,
+        format,
+        size,
+        STATIC,
+    )
+}
+
+fn check_format(format: ImageFormat, supported: &[ImageFormat]) -> Result<(), CdnError> {
+    if supported.contains(&format) {
+        Ok(())
+    } else {
+        Err(CdnError::UnsupportedFormat(format))
+    }
+}
+
+fn image(
+    path: fmt::Arguments<'_>,
+    hash: ImageHash,
+    format: ImageFormat,
+    size: Option<ImageSize>,
+    supported: &[ImageFormat],
+) -> Result<String, CdnError> {
+    check_format(format, supported)?;
+    if format == ImageFormat::Gif && !hash.is_animated() {
+        return Err(CdnError::NotAnimated);
+    }
+
+    // Animated WebP must be requested explicitly, and is static otherwise.
+    let animated = format == ImageFormat::WebP && hash.is_animated();
+    Ok(url(path, format, size, animated))
+}
+
+fn url(
+    path: fmt::Arguments<'_>,
+    format: ImageFormat,
+    size: Option<ImageSize>,
+    animated: bool,
+) -> String {
+    let mut url = format!("{CDN_URL}/{path}.{format}");
+
+    let mut separator = '?';
+    if let Some(size) = size {
+        let _ = write!(url, "{separator}size={}", size.get());
+        separator = '&';
+    }
+    if animated {
+        let _ = write!(url, "{separator}animated=true");
+    }
+
+    url
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroU16;
+
+    use super::{CdnError, ImageFormat, ImageSize, avatar, default_avatar, guild_splash, sticker};
+    use crate::{
+        id::{Snowflake, UserId},
+        image_hash::ImageHash,
+        sticker::StickerFormatType,
+        user::discriminator::Discriminator,
+    };
+
+    const HEX: &str = "8342729096ea3675442027381ff50dfe";
+
+    fn user_id() -> UserId {
+        "80351110224678912".parse().expect("user ID")
+    }
+
+    fn hash(animated: bool) -> ImageHash {
+        let hash = if animated {
+            format!("a_{HEX}")
+        } else {
+            HEX.into()
+        };
+        hash.parse().expect("image hash")
+    }
+
+    #[test]
+    fn image_size() {
+        assert_eq!(ImageSize::new(16).map(ImageSize::get), Some(16));
+        assert_eq!(ImageSize::new(4096).map(ImageSize::get), Some(4096));
+        assert_eq!(ImageSize::new(8), None);
+        assert_eq!(ImageSize::new(100), None);
+        assert_eq!(ImageSize::new(8192), None);
+    }
+
+    #[test]
+    fn static_avatar() {
+        let url = avatar(
+            user_id(),
+            hash(false),
+            ImageFormat::Png,
+            ImageSize::new(256),
+        );
+
+        assert_eq!(
+            url.as_deref(),
+            Ok(
+                format!("https://cdn.discordapp.com/avatars/80351110224678912/{HEX}.png?size=256")
+                    .as_str()
+            ),
+        );
+    }
+
+    #[test]
+    fn rejects_gif_for_static_hash() {
+        assert_eq!(
+            avatar(user_id(), hash(false), ImageFormat::Gif, None),
+            Err(CdnError::NotAnimated),
+        );
+        assert!(avatar(user_id(), hash(true), ImageFormat::Gif, None).is_ok());
+    }
+
+    #[test]
+    fn rejects_unsupported_format() {
+        let guild_id = Snowflake::cast_from(user_id());
+        assert_eq!(
+            guild_splash(guild_id, hash(true), ImageFormat::Gif, None),
+            Err(CdnError::UnsupportedFormat(ImageFormat::Gif)),
+        );
+    }
+
+    #[test]
+    fn animated_webp() {
+        let animated = avatar(user_id(), hash(true), ImageFormat::WebP, ImageSize::new(64));
+        let still = avatar(user_id(), hash(false), ImageFormat::WebP, None);
+
+        assert_eq!(
+            animated.as_deref(),
+            Ok(format!(
+                "https://cdn.discordapp.com/avatars/80351110224678912/a_{HEX}.webp?size=64&animated=true"
+            )
+            .as_str()),
+        );
+        assert_eq!(
+            still.as_deref(),
+            Ok(format!("https://cdn.discordapp.com/avatars/80351110224678912/{HEX}.webp").as_str()),
+        );
+    }
+
+    #[test]
+    fn default_avatar_from_id() {
+        // (80351110224678912 >> 22) % 6
+        assert_eq!(
+            default_avatar(user_id(), None),
+            "https://cdn.discordapp.com/embed/avatars/5.png",
+        );
+    }
+
+    #[test]
+    fn default_avatar_from_legacy_discriminator() {
+        let discriminator = NonZeroU16::new(1337).map(Discriminator);
+        // 1337 % 5
+        assert_eq!(
+            default_avatar(user_id(), discriminator),
+            "https://cdn.discordapp.com/embed/avatars/2.png",
+        );
+    }
+
+    #[test]
+    fn sticker_host_and_extension() {
+        let id = Snowflake::cast_from(user_id());
+
+        assert_eq!(
+            sticker(id, StickerFormatType::Png).as_deref(),
+            Some("https://cdn.discordapp.com/stickers/80351110224678912.png"),
+        );
+        assert_eq!(
+            sticker(id, StickerFormatType::Apng).as_deref(),
+            Some("https://cdn.discordapp.com/stickers/80351110224678912.png"),
+        );
+        assert_eq!(
+            sticker(id, StickerFormatType::Lottie).as_deref(),
+            Some("https://cdn.discordapp.com/stickers/80351110224678912.json"),
+        );
+        assert_eq!(
+            sticker(id, StickerFormatType::Gif).as_deref(),
+            Some("https://media.discordapp.net/stickers/80351110224678912.gif"),
+        );
+        assert_eq!(sticker(id, StickerFormatType::Unknown(9)), None);
+    }
+}
@@ -0,0 +1,29 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    channel::PermissionOverwrite,
+    id::{ChannelId, GuildId},
+};
+
+/// Organizational category that contains up to 50 channels.
+///
+/// <https://discord.com/developers/docs/resources/channel#channel-object>
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CategoryChannel {
+    /// ID of this channel
+    pub id: ChannelId,
+    /// ID of the guild, may be missing for some channel objects received over gateway
+    /// guild dispatches
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub guild_id: Option<GuildId>,
+    /// Sorting position of the channel
+    pub position: i32,
+    /// Explicit permission overwrites for members and roles
+    #[serde(default)]
+    pub permission_overwrites: Vec<PermissionOverwrite>,
+    /// Name of the channel (1-100 characters)
+    pub name: String,
+    /// Whether the channel is nsfw
+    #[serde(default)]
+    pub nsfw: bool,
+}
@@ -0,0 +1,30 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    channel::PermissionOverwrite,
+    id::{ChannelId, GuildId},
+};
+
+/// Channel in a [hub](https://support.discord.com/hc/en-us/articles/4406046651927)
+/// containing the listed servers.
+///
+/// <https://discord.com/developers/docs/resources/channel#channel-object>
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DirectoryChannel {
+    /// ID of this channel
+    pub id: ChannelId,
+    /// ID of the guild, may be missing for some channel objects received over gateway
+    /// guild dispatches
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub guild_id: Option<GuildId>,
+    /// Sorting position of the channel
+    pub position: i32,
+    /// Explicit permission overwrites for members and roles
+    #[serde(default)]
+    pub permission_overwrites: Vec<PermissionOverwrite>,
+    /// Name of the channel (1-100 characters)
+    pub name: String,
+    /// ID of the parent category for this channel
+    #[serde(default)]
+    pub parent_id: Option<ChannelId>,
+}
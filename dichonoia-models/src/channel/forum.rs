@@ -0,0 +1,119 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    channel::{ChannelFlags, PermissionOverwrite, thread::AutoArchiveDuration},
+    id::{ChannelId, EmojiId, ForumTagId, GuildId},
+};
+
+/// Channel that can only contain threads, used for both forum and media channels.
+///
+/// <https://discord.com/developers/docs/topics/threads#forums>
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ForumChannel {
+    /// ID of this channel
+    pub id: ChannelId,
+    /// ID of the guild, may be missing for some channel objects received over gateway
+    /// guild dispatches
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub guild_id: Option<GuildId>,
+    /// Sorting position of the channel
+    pub position: i32,
+    /// Explicit permission overwrites for members and roles
+    #[serde(default)]
+    pub permission_overwrites: Vec<PermissionOverwrite>,
+    /// Name of the channel (1-100 characters)
+    pub name: String,
+    /// Guidelines shown when creating a post (0-4096 characters)
+    #[serde(default)]
+    pub topic: Option<String>,
+    /// Whether the channel is nsfw
+    #[serde(default)]
+    pub nsfw: bool,
+    /// ID of the last thread created in this channel (may not point to an existing or
+    /// valid thread)
+    #[serde(default)]
+    pub last_message_id: Option<ChannelId>,
+    /// Amount of seconds a user has to wait before creating another post (0-21600)
+    #[serde(default)]
+    pub rate_limit_per_user: u16,
+    /// ID of the parent category for this channel
+    #[serde(default)]
+    pub parent_id: Option<ChannelId>,
+    /// [Channel flags](ChannelFlags)
+    #[serde(default)]
+    pub flags: ChannelFlags,
+    /// Tags that can be used in the channel
+    #[serde(default)]
+    pub available_tags: Vec<ForumTag>,
+    /// Emoji to show in the add reaction button on a thread
+    #[serde(default)]
+    pub default_reaction_emoji: Option<DefaultReaction>,
+    /// Default duration for newly created threads, used by the clients
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_auto_archive_duration: Option<AutoArchiveDuration>,
+    /// Initial `rate_limit_per_user` set on newly created threads in this channel
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_thread_rate_limit_per_user: Option<u16>,
+    /// Default [sort order type](SortOrderType) used to order posts
+    #[serde(default)]
+    pub default_sort_order: Option<SortOrderType>,
+    /// Default [forum layout view](ForumLayoutType) used to display posts; only set on
+    /// forum channels
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_forum_layout: Option<ForumLayoutType>,
+}
+
+/// Forum Tag Object
+///
+/// <https://discord.com/developers/docs/resources/channel#forum-tag-object>
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ForumTag {
+    /// ID of the tag
+    pub id: ForumTagId,
+    /// Name of the tag (0-20 characters)
+    pub name: String,
+    /// Whether this tag can only be added to or removed from threads by a member with
+    /// the `MANAGE_THREADS` permission
+    pub moderated: bool,
+    /// ID of a guild's custom emoji
+    pub emoji_id: Option<EmojiId>,
+    /// Unicode character of the emoji
+    pub emoji_name: Option<String>,
+}
+
+/// Default Reaction Object
+///
+/// <https://discord.com/developers/docs/resources/channel#default-reaction-object>
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DefaultReaction {
+    /// ID of a guild's custom emoji
+    pub emoji_id: Option<EmojiId>,
+    /// Unicode character of the emoji
+    pub emoji_name: Option<String>,
+}
+
+int_enum! {
+    /// Sort Order Types
+    ///
+    /// <https://discord.com/developers/docs/resources/channel#channel-object-sort-order-types>
+    pub enum SortOrderType: u8 {
+        /// Sort forum posts by activity
+        LatestActivity = 0,
+        /// Sort forum posts by creation time (from most recent to oldest)
+        CreationDate = 1,
+    }
+}
+
+int_enum! {
+    /// Forum Layout Types
+    ///
+    /// <https://discord.com/developers/docs/resources/channel#channel-object-forum-layout-types>
+    pub enum ForumLayoutType: u8 {
+        /// No default has been set for forum channel
+        NotSet = 0,
+        /// Display posts as a list
+        ListView = 1,
+        /// Display posts as a collection of tiles
+        GalleryView = 2,
+    }
+}
@@ -0,0 +1,176 @@
+pub mod category;
+pub mod directory;
+pub mod forum;
+pub mod permission_overwrite;
+pub mod private;
+pub mod text;
+pub mod thread;
+pub mod voice;
+
+pub use category::CategoryChannel;
+pub use directory::DirectoryChannel;
+pub use forum::ForumChannel;
+pub use permission_overwrite::{PermissionOverwrite, PermissionOverwriteTarget};
+pub use private::{DmChannel, GroupDmChannel};
+pub use text::TextChannel;
+pub use thread::ThreadChannel;
+pub use voice::VoiceChannel;
+
+use bitflags::bitflags;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::Value;
+
+use crate::{content::Content, id::ChannelId};
+
+/// Defines [`Channel`] and [`ChannelType`] from the type of each channel and its model,
+/// so that both always stay in sync.
+macro_rules! channels {
+    ( $( $(#[$meta:meta])* $variant:ident($ty:ty) = $value:literal ),+ $(,)? ) => {
+        /// Channel Object
+        ///
+        /// Represents a guild or DM channel within Discord, split by its
+        /// [type](ChannelType) so that each variant only carries the fields valid for it.
+        ///
+        /// <https://discord.com/developers/docs/resources/channel#channel-object>
+        #[derive(Debug, Clone)]
+        pub enum Channel {
+            $( $(#[$meta])* $variant($ty), )+
+            /// Channel of a type that is not modeled by this library, with its raw data.
+            Unknown {
+                /// ID of this channel
+                id: ChannelId,
+                /// Raw type of this channel
+                kind: u8,
+                /// Raw data of this channel
+                data: Value,
+            },
+        }
+
+        int_enum! {
+            /// Channel Types
+            ///
+            /// <https://discord.com/developers/docs/resources/channel#channel-object-channel-types>
+            pub enum ChannelType: u8 {
+                $( $(#[$meta])* $variant = $value, )+
+            }
+        }
+
+        impl Channel {
+            /// ID of this channel.
+            #[must_use]
+            pub const fn id(&self) -> ChannelId {
+                match self {
+                    $( Self::$variant(channel) => channel.id, )+
+                    Self::Unknown { id, .. } => *id,
+                }
+            }
+
+            /// Type of this channel, or `None` if it is not modeled by this library.
+            #[must_use]
+            pub const fn kind(&self) -> Option<ChannelType> {
+                match self {
+                    $( Self::$variant(_) => Some(ChannelType::$variant), )+
+                    Self::Unknown { .. } => None,
+                }
+            }
+        }
+
+        impl Serialize for Channel {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                match self {
+                    $(
+                        Self::$variant(channel) => TaggedChannel {
+                            kind: ChannelType::$variant,
+                            channel,
+                        }
+                        .serialize(serializer),
+                    )+
+                    Self::Unknown { data, .. } => data.serialize(serializer),
+                }
+            }
+        }
+
+        impl<'de> Deserialize<'de> for Channel {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                let content = Content::deserialize(deserializer)?;
+                let id = content.required_field("id")?;
+                let kind = content.required_field("type")?;
+
+                match kind {
+                    $( $value => content.deserialize_into().map(Self::$variant), )+
+                    _ => Ok(Self::Unknown {
+                        id,
+                        kind,
+                        data: content.deserialize_into()?,
+                    }),
+                }
+            }
+        }
+    };
+}
+
+channels! {
+    /// Text channel within a server
+    GuildText(TextChannel) = 0,
+    /// Direct message between users
+    Dm(DmChannel) = 1,
+    /// Voice channel within a server
+    GuildVoice(VoiceChannel) = 2,
+    /// Direct message between multiple users
+    GroupDm(GroupDmChannel) = 3,
+    /// Organizational category that contains up to 50 channels
+    GuildCategory(CategoryChannel) = 4,
+    /// Channel that users can follow and crosspost into their own server (formerly
+    /// news channels)
+    GuildAnnouncement(TextChannel) = 5,
+    /// Temporary sub-channel within a `GuildAnnouncement` channel
+    AnnouncementThread(ThreadChannel) = 10,
+    /// Temporary sub-channel within a `GuildText` or `GuildForum` channel
+    PublicThread(ThreadChannel) = 11,
+    /// Temporary sub-channel within a `GuildText` channel that is only viewable by
+    /// those invited and those with the `MANAGE_THREADS` permission
+    PrivateThread(ThreadChannel) = 12,
+    /// Voice channel for hosting events with an audience
+    GuildStageVoice(VoiceChannel) = 13,
+    /// Channel in a hub containing the listed servers
+    GuildDirectory(DirectoryChannel) = 14,
+    /// Channel that can only contain threads
+    GuildForum(ForumChannel) = 15,
+    /// Channel that can only contain threads, similar to `GuildForum` channels
+    GuildMedia(ForumChannel) = 16,
+}
+
+/// Channel as it is sent by Discord, with its type next to its fields.
+#[derive(Serialize)]
+struct TaggedChannel<'a, T> {
+    #[serde(rename = "type")]
+    kind: ChannelType,
+    #[serde(flatten)]
+    channel: &'a T,
+}
+
+bitflags! {
+    /// Channel Flags
+    ///
+    /// <https://discord.com/developers/docs/resources/channel#channel-object-channel-flags>
+    #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+    pub struct ChannelFlags: u32 {
+        /// This thread is pinned to the top of its parent `GuildForum` or `GuildMedia`
+        /// channel
+        const PINNED = 1 << 1;
+        /// Whether a tag is required to be specified when creating a thread in a
+        /// `GuildForum` or a `GuildMedia` channel
+        const REQUIRE_TAG = 1 << 4;
+        /// When set hides the embedded media download options, available only for media
+        /// channels
+        const HIDE_MEDIA_DOWNLOAD_OPTIONS = 1 << 15;
+    }
+}
+
+bitflags_serde!(ChannelFlags);
@@ -0,0 +1,95 @@
+use std::borrow::Cow;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::id::{RoleId, UserId};
+
+/// Overwrite Object
+///
+/// Explicit permission overwrites for a role or a member, applied on top of the base
+/// permissions of the guild.
+///
+/// <https://discord.com/developers/docs/resources/channel#overwrite-object>
+#[derive(Debug, Clone)]
+pub struct PermissionOverwrite {
+    /// Role or member the overwrite applies to
+    pub target: PermissionOverwriteTarget,
+    /// Permission bit set of allowed permissions
+    pub allow: String,
+    /// Permission bit set of denied permissions
+    pub deny: String,
+}
+
+/// Role or member a [`PermissionOverwrite`] applies to.
+///
+/// Discord sends this as an `id` and a `type` field, where the type tells whether the
+/// ID is a role ID or a user ID.
+#[derive(Debug, Clone, Copy)]
+pub enum PermissionOverwriteTarget {
+    Role(RoleId),
+    Member(UserId),
+}
+
+/// The ID is either a role ID or a user ID depending on `kind`, and is cast accordingly.
+#[derive(Serialize, Deserialize)]
+struct RawPermissionOverwrite<'a> {
+    id: RoleId,
+    #[serde(rename = "type")]
+    kind: PermissionOverwriteType,
+    allow: Cow<'a, str>,
+    deny: Cow<'a, str>,
+}
+
+int_enum! {
+    /// <https://discord.com/developers/docs/resources/channel#overwrite-object-overwrite-structure>
+    enum PermissionOverwriteType: u8 {
+        Role = 0,
+        Member = 1,
+    }
+}
+
+impl Serialize for PermissionOverwrite {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let (id, kind) = match self.target {
+            PermissionOverwriteTarget::Role(id) => (id, PermissionOverwriteType::Role),
+            PermissionOverwriteTarget::Member(id) => {
+                (id.cast_into(), PermissionOverwriteType::Member)
+            }
+        };
+        RawPermissionOverwrite {
+            id,
+            kind,
+            allow: Cow::Borrowed(&self.allow),
+            deny: Cow::Borrowed(&self.deny),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for PermissionOverwrite {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let raw = RawPermissionOverwrite::deserialize(deserializer)?;
+        Ok(Self {
+            target: match raw.kind {
+                PermissionOverwriteType::Role => PermissionOverwriteTarget::Role(raw.id),
+                PermissionOverwriteType::Member => {
+                    PermissionOverwriteTarget::Member(raw.id.cast_into())
+                }
+                PermissionOverwriteType::Unknown(kind) => {
+                    return Err(serde::de::Error::invalid_value(
+                        serde::de::Unexpected::Unsigned(kind.into()),
+                        &"a role (0) or member (1) overwrite type",
+                    ));
+                }
+            },
+            allow: raw.allow.into_owned(),
+            deny: raw.deny.into_owned(),
+        })
+    }
+}
@@ -0,0 +1,60 @@
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+
+use crate::{
+    id::{ApplicationId, ChannelId, MessageId, UserId},
+    image_hash::ImageHash,
+    user::User,
+};
+
+/// Direct message between users.
+///
+/// <https://discord.com/developers/docs/resources/channel#channel-object>
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DmChannel {
+    /// ID of this channel
+    pub id: ChannelId,
+    /// Recipients of the DM
+    #[serde(default)]
+    pub recipients: Vec<User>,
+    /// ID of the last message sent in this channel (may not point to an existing or
+    /// valid message)
+    #[serde(default)]
+    pub last_message_id: Option<MessageId>,
+    /// When the last pinned message was pinned
+    #[serde(default, with = "time::serde::rfc3339::option")]
+    pub last_pin_timestamp: Option<OffsetDateTime>,
+}
+
+/// Direct message between multiple users.
+///
+/// <https://discord.com/developers/docs/resources/channel#channel-object>
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GroupDmChannel {
+    /// ID of this channel
+    pub id: ChannelId,
+    /// Name of the channel (1-100 characters)
+    #[serde(default)]
+    pub name: Option<String>,
+    /// Icon hash of the group DM
+    #[serde(default)]
+    pub icon: Option<ImageHash>,
+    /// Recipients of the DM
+    #[serde(default)]
+    pub recipients: Vec<User>,
+    /// ID of the creator of the group DM
+    pub owner_id: UserId,
+    /// Application ID of the group DM creator if it is bot-created
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub application_id: Option<ApplicationId>,
+    /// Whether the channel is managed by an application via the `gdm.join` scope
+    #[serde(default)]
+    pub managed: bool,
+    /// ID of the last message sent in this channel (may not point to an existing or
+    /// valid message)
+    #[serde(default)]
+    pub last_message_id: Option<MessageId>,
+    /// When the last pinned message was pinned
+    #[serde(default, with = "time::serde::rfc3339::option")]
+    pub last_pin_timestamp: Option<OffsetDateTime>,
+}
@@ -0,0 +1,54 @@
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+
+use crate::{
+    channel::{PermissionOverwrite, thread::AutoArchiveDuration},
+    id::{ChannelId, GuildId, MessageId},
+};
+
+/// Text channel within a server, also used for announcement channels.
+///
+/// <https://discord.com/developers/docs/resources/channel#channel-object>
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TextChannel {
+    /// ID of this channel
+    pub id: ChannelId,
+    /// ID of the guild, may be missing for some channel objects received over gateway
+    /// guild dispatches
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub guild_id: Option<GuildId>,
+    /// Sorting position of the channel
+    pub position: i32,
+    /// Explicit permission overwrites for members and roles
+    #[serde(default)]
+    pub permission_overwrites: Vec<PermissionOverwrite>,
+    /// Name of the channel (1-100 characters)
+    pub name: String,
+    /// Channel topic (0-4096 characters)
+    #[serde(default)]
+    pub topic: Option<String>,
+    /// Whether the channel is nsfw
+    #[serde(default)]
+    pub nsfw: bool,
+    /// Amount of seconds a user has to wait before sending another message (0-21600);
+    /// bots, as well as users with the permission `MANAGE_MESSAGES` or `MANAGE_CHANNEL`,
+    /// are unaffected
+    #[serde(default)]
+    pub rate_limit_per_user: u16,
+    /// ID of the parent category for this channel
+    #[serde(default)]
+    pub parent_id: Option<ChannelId>,
+    /// ID of the last message sent in this channel (may not point to an existing or
+    /// valid message)
+    #[serde(default)]
+    pub last_message_id: Option<MessageId>,
+    /// When the last pinned message was pinned
+    #[serde(default, with = "time::serde::rfc3339::option")]
+    pub last_pin_timestamp: Option<OffsetDateTime>,
+    /// Default duration for newly created threads, used by the clients
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_auto_archive_duration: Option<AutoArchiveDuration>,
+    /// Initial `rate_limit_per_user` set on newly created threads in this channel
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_thread_rate_limit_per_user: Option<u16>,
+}
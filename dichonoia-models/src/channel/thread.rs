@@ -0,0 +1,126 @@
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+
+use crate::{
+    channel::ChannelFlags,
+    guild::member::Member,
+    id::{ChannelId, ForumTagId, GuildId, MessageId, UserId},
+};
+
+/// Temporary sub-channel within a text, announcement, forum or media channel.
+///
+/// <https://discord.com/developers/docs/topics/threads>
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ThreadChannel {
+    /// ID of this channel
+    pub id: ChannelId,
+    /// ID of the guild, may be missing for some channel objects received over gateway
+    /// guild dispatches
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub guild_id: Option<GuildId>,
+    /// Name of the thread (1-100 characters)
+    pub name: String,
+    /// ID of the text channel this thread was created in
+    pub parent_id: ChannelId,
+    /// ID of the creator of the thread
+    pub owner_id: UserId,
+    /// ID of the last message sent in this thread (may not point to an existing or valid
+    /// message)
+    #[serde(default)]
+    pub last_message_id: Option<MessageId>,
+    /// Amount of seconds a user has to wait before sending another message (0-21600)
+    #[serde(default)]
+    pub rate_limit_per_user: u16,
+    /// Number of messages (not including the initial message or deleted messages) in the
+    /// thread
+    #[serde(default)]
+    pub message_count: u32,
+    /// Approximate count of users in the thread, stops counting at 50
+    #[serde(default)]
+    pub member_count: u32,
+    /// Thread-specific fields not needed by other channels
+    pub thread_metadata: ThreadMetadata,
+    /// Thread member object for the current user, if they have joined the thread
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub member: Option<ThreadMember>,
+    /// Number of messages ever sent in the thread
+    #[serde(default)]
+    pub total_message_sent: u32,
+    /// [Channel flags](ChannelFlags)
+    #[serde(default)]
+    pub flags: ChannelFlags,
+    /// IDs of the tags applied to a thread in a `GuildForum` or a `GuildMedia` channel
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub applied_tags: Vec<ForumTagId>,
+}
+
+/// Thread Metadata Object
+///
+/// <https://discord.com/developers/docs/resources/channel#thread-metadata-object>
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ThreadMetadata {
+    /// Whether the thread is archived
+    pub archived: bool,
+    /// The thread will stop showing in the channel list after this duration of
+    /// inactivity
+    pub auto_archive_duration: AutoArchiveDuration,
+    /// When the thread's archive status was last changed, used for calculating recent
+    /// activity
+    #[serde(with = "time::serde::rfc3339")]
+    pub archive_timestamp: OffsetDateTime,
+    /// Whether the thread is locked; when a thread is locked, only users with
+    /// `MANAGE_THREADS` can unarchive it
+    pub locked: bool,
+    /// Whether non-moderators can add other non-moderators to a thread; only available
+    /// on private threads
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub invitable: Option<bool>,
+    /// When the thread was created; only populated for threads created after 2022-01-09
+    #[serde(default, with = "time::serde::rfc3339::option")]
+    pub create_timestamp: Option<OffsetDateTime>,
+}
+
+/// Thread Member Object
+///
+/// <https://discord.com/developers/docs/resources/channel#thread-member-object>
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ThreadMember {
+    /// ID of the thread, omitted on the member sent within each thread in Guild Create
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<ChannelId>,
+    /// ID of the user, omitted on the member sent within each thread in Guild Create
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<UserId>,
+    /// Time the user last joined the thread
+    #[serde(with = "time::serde::rfc3339")]
+    pub join_timestamp: OffsetDateTime,
+    /// Any user-thread settings, currently only used for notifications
+    pub flags: u32,
+    /// Guild member of the user
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub member: Option<Box<Member>>,
+}
+
+int_enum! {
+    /// Duration of inactivity, in minutes, after which a thread is archived
+    ///
+    /// <https://discord.com/developers/docs/resources/channel#thread-metadata-object>
+    pub enum AutoArchiveDuration: u16 {
+        /// 1 hour
+        OneHour = 60,
+        /// 1 day
+        OneDay = 1440,
+        /// 3 days
+        ThreeDays = 4320,
+        /// 1 week
+        OneWeek = 10080,
+    }
+}
+
+impl AutoArchiveDuration {
+    /// Duration of the inactivity.
+    #[must_use]
+    pub fn duration(self) -> std::time::Duration {
+        std::time::Duration::from_secs(u64::from(u16::from(self)) * 60)
+    }
+}
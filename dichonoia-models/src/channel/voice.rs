@@ -0,0 +1,62 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    channel::PermissionOverwrite,
+    id::{ChannelId, GuildId, MessageId},
+};
+
+/// Voice channel within a server, also used for stage channels.
+///
+/// <https://discord.com/developers/docs/resources/channel#channel-object>
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VoiceChannel {
+    /// ID of this channel
+    pub id: ChannelId,
+    /// ID of the guild, may be missing for some channel objects received over gateway
+    /// guild dispatches
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub guild_id: Option<GuildId>,
+    /// Sorting position of the channel
+    pub position: i32,
+    /// Explicit permission overwrites for members and roles
+    #[serde(default)]
+    pub permission_overwrites: Vec<PermissionOverwrite>,
+    /// Name of the channel (1-100 characters)
+    pub name: String,
+    /// Whether the channel is nsfw
+    #[serde(default)]
+    pub nsfw: bool,
+    /// Amount of seconds a user has to wait before sending another message (0-21600)
+    #[serde(default)]
+    pub rate_limit_per_user: u16,
+    /// ID of the last message sent in this channel (may not point to an existing or
+    /// valid message)
+    #[serde(default)]
+    pub last_message_id: Option<MessageId>,
+    /// Bitrate (in bits) of the voice channel
+    pub bitrate: u32,
+    /// User limit of the voice channel, or 0 for no limit
+    pub user_limit: u32,
+    /// ID of the parent category for this channel
+    #[serde(default)]
+    pub parent_id: Option<ChannelId>,
+    /// Voice region ID for the voice channel, automatic when set to `None`
+    #[serde(default)]
+    pub rtc_region: Option<String>,
+    /// Camera [video quality mode](VideoQualityMode) of the voice channel, automatic
+    /// when not present
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub video_quality_mode: Option<VideoQualityMode>,
+}
+
+int_enum! {
+    /// Video Quality Modes
+    ///
+    /// <https://discord.com/developers/docs/resources/channel#channel-object-video-quality-modes>
+    pub enum VideoQualityMode: u8 {
+        /// Discord chooses the quality for optimal performance
+        Auto = 1,
+        /// 720p
+        Full = 2,
+    }
+}
@@ -0,0 +1,365 @@
+//! Buffering of a value of any self-describing format.
+//!
+//! Some models can only be picked after reading one of their fields, such as the `type`
+//! of a channel, which may come after every other field. [`Content`] keeps the value in
+//! memory so that it can be deserialized again into the model that was picked, without
+//! going through JSON, and while reporting errors in the format it was received in.
+
+use std::borrow::Cow;
+use std::fmt;
+use std::marker::PhantomData;
+
+use serde::de::value::{MapAccessDeserializer, MapDeserializer, SeqDeserializer};
+use serde::de::{self, Deserialize, Deserializer, IntoDeserializer, MapAccess, SeqAccess, Visitor};
+use serde::forward_to_deserialize_any;
+
+/// Value of any self-describing format, buffered to be deserialized later.
+#[derive(Debug, Clone)]
+pub enum Content<'de> {
+    Bool(bool),
+    U64(u64),
+    I64(i64),
+    F64(f64),
+    Str(Cow<'de, str>),
+    Bytes(Cow<'de, [u8]>),
+    None,
+    Some(Box<Self>),
+    Unit,
+    Seq(Vec<Self>),
+    Map(Vec<(Self, Self)>),
+}
+
+impl<'de> Content<'de> {
+    /// Deserializes the field called `name` if this is a map, or returns `None` if the
+    /// field is missing.
+    ///
+    /// The field is cloned, so this is meant for the small fields that pick a model.
+    pub fn field<T, E>(&self, name: &str) -> Result<Option<T>, E>
+    where
+        T: Deserialize<'de>,
+        E: de::Error,
+    {
+        let Self::Map(entries) = self else {
+            return Ok(None);
+        };
+
+        entries
+            .iter()
+            .find(|(key, _)| matches!(key, Self::Str(key) if key == name))
+            .map(|(_, value)| T::deserialize(ContentDeserializer::new(value.clone())))
+            .transpose()
+    }
+
+    /// Like [`Content::field`], but fails if the field is missing.
+    pub fn required_field<T, E>(&self, name: &'static str) -> Result<T, E>
+    where
+        T: Deserialize<'de>,
+        E: de::Error,
+    {
+        self.field(name)?.ok_or_else(|| E::missing_field(name))
+    }
+
+    /// Removes the field called `name` if this is a map, to deserialize it on its own.
+    pub fn take_field(&mut self, name: &str) -> Option<Self> {
+        let Self::Map(entries) = self else {
+            return None;
+        };
+
+        let index = entries
+            .iter()
+            .position(|(key, _)| matches!(key, Self::Str(key) if key == name))?;
+        Some(entries.swap_remove(index).1)
+    }
+
+    /// Deserializes the buffered value into `T`.
+    pub fn deserialize_into<T, E>(self) -> Result<T, E>
+    where
+        T: Deserialize<'de>,
+        E: de::Error,
+    {
+        T::deserialize(ContentDeserializer::new(self))
+    }
+}
+
+impl<'de> Deserialize<'de> for Content<'de> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(ContentVisitor)
+    }
+}
+
+struct ContentVisitor;
+
+impl<'de> Visitor<'de> for ContentVisitor {
+    type Value = Content<'de>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("any value")
+    }
+
+    fn visit_bool<E: de::Error>(self, v: bool) -> Result<Self::Value, E> {
+        Ok(Content::Bool(v))
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<Self::Value, E> {
+        Ok(Content::I64(v))
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<Self::Value, E> {
+        Ok(Content::U64(v))
+    }
+
+    fn visit_f64<E: de::Error>(self, v: f64) -> Result<Self::Value, E> {
+        Ok(Content::F64(v))
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        Ok(Content::Str(Cow::Owned(v.to_owned())))
+    }
+
+    fn visit_borrowed_str<E: de::Error>(self, v: &'de str) -> Result<Self::Value, E> {
+        Ok(Content::Str(Cow::Borrowed(v)))
+    }
+
+    fn visit_string<E: de::Error>(self, v: String) -> Result<Self::Value, E> {
+        Ok(Content::Str(Cow::Owned(v)))
+    }
+
+    fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+        Ok(Content::Bytes(Cow::Owned(v.to_owned())))
+    }
+
+    fn visit_borrowed_bytes<E: de::Error>(self, v: &'de [u8]) -> Result<Self::Value, E> {
+        Ok(Content::Bytes(Cow::Borrowed(v)))
+    }
+
+    fn visit_byte_buf<E: de::Error>(self, v: Vec<u8>) -> Result<Self::Value, E> {
+        Ok(Content::Bytes(Cow::Owned(v)))
+    }
+
+    fn visit_none<E: de::Error>(self) -> Result<Self::Value, E> {
+        Ok(Content::None)
+    }
+
+    fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Content::deserialize(deserializer).map(|content| Content::Some(Box::new(content)))
+    }
+
+    fn visit_unit<E: de::Error>(self) -> Result<Self::Value, E> {
+        Ok(Content::Unit)
+    }
+
+    fn visit_newtype_struct<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Content::deserialize(deserializer)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut elements = Vec::with_capacity(seq.size_hint().unwrap_or(0).min(4096));
+        while let Some(element) = seq.next_element()? {
+            elements.push(element);
+        }
+
+        Ok(Content::Seq(elements))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut entries = Vec::with_capacity(map.size_hint().unwrap_or(0).min(4096));
+        while let Some(entry) = map.next_entry()? {
+            entries.push(entry);
+        }
+
+        Ok(Content::Map(entries))
+    }
+}
+
+/// Deserializer of a buffered [`Content`], reporting errors of type `E`.
+pub struct ContentDeserializer<'de, E> {
+    content: Content<'de>,
+    error: PhantomData<E>,
+}
+
+impl<'de, E> ContentDeserializer<'de, E> {
+    pub const fn new(content: Content<'de>) -> Self {
+        Self {
+            content,
+            error: PhantomData,
+        }
+    }
+}
+
+impl<'de, E: de::Error> IntoDeserializer<'de, E> for Content<'de> {
+    type Deserializer = ContentDeserializer<'de, E>;
+
+    fn into_deserializer(self) -> Self::Deserializer {
+        ContentDeserializer::new(self)
+    }
+}
+
+fn visit_seq<'de, V, E>(elements: Vec<Content<'de>>, visitor: V) -> Result<V::Value, E>
+where
+    V: Visitor<'de>,
+    E: de::Error,
+{
+    let mut seq = SeqDeserializer::new(elements.into_iter());
+    let value = visitor.visit_seq(&mut seq)?;
+    seq.end()?;
+    Ok(value)
+}
+
+fn visit_map<'de, V, E>(
+    entries: Vec<(Content<'de>, Content<'de>)>,
+    visitor: V,
+) -> Result<V::Value, E>
+where
+    V: Visitor<'de>,
+    E: de::Error,
+{
+    let mut map = MapDeserializer::new(entries.into_iter());
+    let value = visitor.visit_map(&mut map)?;
+    map.end()?;
+    Ok(value)
+}
+
+impl<'de, E: de::Error> Deserializer<'de> for ContentDeserializer<'de, E> {
+    type Error = E;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, E> {
+        match self.content {
+            Content::Bool(v) => visitor.visit_bool(v),
+            Content::U64(v) => visitor.visit_u64(v),
+            Content::I64(v) => visitor.visit_i64(v),
+            Content::F64(v) => visitor.visit_f64(v),
+            Content::Str(Cow::Borrowed(v)) => visitor.visit_borrowed_str(v),
+            Content::Str(Cow::Owned(v)) => visitor.visit_string(v),
+            Content::Bytes(Cow::Borrowed(v)) => visitor.visit_borrowed_bytes(v),
+            Content::Bytes(Cow::Owned(v)) => visitor.visit_byte_buf(v),
+            Content::None => visitor.visit_none(),
+            Content::Some(content) => visitor.visit_some(Self::new(*content)),
+            Content::Unit => visitor.visit_unit(),
+            Content::Seq(elements) => visit_seq(elements, visitor),
+            Content::Map(entries) => visit_map(entries, visitor),
+        }
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, E> {
+        match self.content {
+            Content::None | Content::Unit => visitor.visit_none(),
+            Content::Some(content) => visitor.visit_some(Self::new(*content)),
+            content => visitor.visit_some(Self::new(content)),
+        }
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, E> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, E> {
+        match self.content {
+            Content::Str(variant) => visitor.visit_enum(variant.into_deserializer()),
+            Content::Map(entries) if entries.len() == 1 => {
+                let map = MapDeserializer::new(entries.into_iter());
+                visitor.visit_enum(MapAccessDeserializer::new(map))
+            }
+            content => Self::new(content).deserialize_any(visitor),
+        }
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string bytes
+        byte_buf unit unit_struct seq tuple tuple_struct map struct identifier ignored_any
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::{
+        gateway::{GatewayPayload, events::GuildCreate},
+        message::component::{Component, ComponentType},
+    };
+
+    #[test]
+    fn type_after_fields() {
+        let json = br#"{ "content": "hi", "id": 3, "type": 10 }"#;
+        let component: Component = serde_json::from_slice(json).expect("text display");
+
+        let Component::TextDisplay(text) = component else {
+            panic!("expected a text display, got {component:?}");
+        };
+        assert_eq!((text.id, text.content.as_str()), (Some(3), "hi"));
+    }
+
+    #[test]
+    fn unknown_type_keeps_data() {
+        let data = json!({ "type": 99, "items": [1, null, { "a": "b" }], "spoiler": true });
+        let component: Component = serde_json::from_value(data.clone()).expect("component");
+
+        let Component::Unknown { kind, data: raw } = component else {
+            panic!("expected an unknown component, got {component:?}");
+        };
+        assert_eq!(ComponentType::from(kind), ComponentType::Unknown(99));
+        assert_eq!(raw, data);
+    }
+
+    #[test]
+    fn errors_come_from_the_format() {
+        let missing = serde_json::from_str::<Component>(r#"{ "content": "hi" }"#);
+        let invalid = serde_json::from_str::<Component>(r#"{ "type": 10, "content": 1 }"#);
+
+        assert_eq!(
+            missing.expect_err("missing type").to_string(),
+            "missing field `type`",
+        );
+        assert!(
+            invalid
+                .expect_err("invalid content")
+                .to_string()
+                .starts_with("invalid type: integer `1`, expected a string"),
+        );
+    }
+
+    #[test]
+    fn unavailable_guild_create() {
+        let data = json!({ "id": "41771983423143937", "unavailable": true });
+        let guild: GuildCreate = serde_json::from_value(data).expect("guild create");
+
+        assert!(matches!(
+            guild,
+            GuildCreate::Unavailable(id) if id.get() == 41_771_983_423_143_937
+        ));
+    }
+
+    #[test]
+    fn payload_from_slice_and_value() {
+        let json = br#"{ "d": { "heartbeat_interval": 41250 }, "s": null, "op": 10 }"#;
+        let value = serde_json::from_slice(json).expect("json");
+
+        for payload in [
+            GatewayPayload::from_slice(json),
+            GatewayPayload::from_json(&value),
+        ] {
+            assert!(matches!(
+                payload,
+                Ok(GatewayPayload::Hello(hello)) if hello.heartbeat_interval == 41250
+            ));
+        }
+    }
+}
@@ -0,0 +1,36 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    id::{EmojiId, RoleId},
+    user::User,
+};
+
+/// Emoji Object
+///
+/// <https://discord.com/developers/docs/resources/emoji#emoji-object>
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[expect(clippy::struct_excessive_bools)]
+pub struct Emoji {
+    /// Emoji ID, or `None` for a standard emoji
+    pub id: Option<EmojiId>,
+    /// Emoji name, or `None` for a custom emoji in a reaction whose name is unknown
+    pub name: Option<String>,
+    /// Roles allowed to use this emoji
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub roles: Vec<RoleId>,
+    /// User that created this emoji
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user: Option<Box<User>>,
+    /// Whether this emoji must be wrapped in colons
+    #[serde(default)]
+    pub require_colons: bool,
+    /// Whether this emoji is managed
+    #[serde(default)]
+    pub managed: bool,
+    /// Whether this emoji is animated
+    #[serde(default)]
+    pub animated: bool,
+    /// Whether this emoji can be used, may be `false` due to loss of Server Boosts
+    #[serde(default)]
+    pub available: bool,
+}
@@ -0,0 +1,136 @@
+use core::fmt;
+use std::fmt::Display;
+
+/// Gateway Close Event Code
+///
+/// In order to prevent broken reconnect loops, you should consider some close codes as a
+/// signal to stop reconnecting. This can be because your token expired, or your
+/// identification is invalid. See [`CloseCode::can_reconnect`].
+///
+/// <https://discord.com/developers/docs/topics/opcodes-and-status-codes#gateway-gateway-close-event-codes>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(u16)]
+pub enum CloseCode {
+    /// We're not sure what went wrong. Try reconnecting?
+    UnknownError = 4000,
+    /// You sent an invalid Gateway opcode or an invalid payload for an opcode.
+    UnknownOpcode = 4001,
+    /// You sent an invalid payload to Discord.
+    DecodeError = 4002,
+    /// You sent a payload prior to identifying, or this session has been invalidated.
+    NotAuthenticated = 4003,
+    /// The account token sent with your identify payload is incorrect.
+    AuthenticationFailed = 4004,
+    /// You sent more than one identify payload.
+    AlreadyAuthenticated = 4005,
+    /// The sequence sent when resuming the session was invalid. Reconnect and start a new
+    /// session.
+    InvalidSeq = 4007,
+    /// You're sending payloads to Discord too quickly. You will be disconnected on
+    /// receiving this.
+    RateLimited = 4008,
+    /// Your session timed out. Reconnect and start a new one.
+    SessionTimedOut = 4009,
+    /// You sent Discord an invalid [shard when identifying].
+    ///
+    /// [shard when identifying]: crate::gateway::events::ready::shard_info::ShardInfo
+    InvalidShard = 4010,
+    /// The session would have handled too many guilds - you are required to
+    /// [shard] your connection in order to connect.
+    ///
+    /// [shard]: crate::gateway::events::ready::shard_info::ShardInfo
+    ShardingRequired = 4011,
+    /// You sent an invalid version for the gateway.
+    InvalidApiVersion = 4012,
+    /// You sent an invalid intent for a [Gateway Intent]. You may have incorrectly
+    /// calculated the bitwise value.
+    ///
+    /// [Gateway Intent]: crate::gateway::GatewayIntents
+    InvalidIntents = 4013,
+    /// You sent a disallowed intent for a [Gateway Intent]. You may have tried to specify
+    /// an intent that you have not enabled or are not approved for.
+    ///
+    /// [Gateway Intent]: crate::gateway::GatewayIntents
+    DisallowedIntents = 4014,
+}
+
+impl CloseCode {
+    /// Whether it is possible to reconnect after the connection was closed with this code.
+    ///
+    /// Reconnecting after any other code will fail the same way again, and usually
+    /// requires fixing the token, the intents, or the sharding configuration.
+    #[must_use]
+    pub const fn can_reconnect(self) -> bool {
+        !matches!(
+            self,
+            Self::AuthenticationFailed
+                | Self::InvalidShard
+                | Self::ShardingRequired
+                | Self::InvalidApiVersion
+                | Self::InvalidIntents
+                | Self::DisallowedIntents
+        )
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn get(self) -> u16 {
+        self as u16
+    }
+}
+
+impl TryFrom<u16> for CloseCode {
+    /// The code itself, when it is not a documented gateway close code.
+    type Error = u16;
+
+    fn try_from(value: u16) -> Result<Self, Self::Error> {
+        let code = match value {
+            4000 => Self::UnknownError,
+            4001 => Self::UnknownOpcode,
+            4002 => Self::DecodeError,
+            4003 => Self::NotAuthenticated,
+            4004 => Self::AuthenticationFailed,
+            4005 => Self::AlreadyAuthenticated,
+            4007 => Self::InvalidSeq,
+            4008 => Self::RateLimited,
+            4009 => Self::SessionTimedOut,
+            4010 => Self::InvalidShard,
+            4011 => Self::ShardingRequired,
+            4012 => Self::InvalidApiVersion,
+            4013 => Self::InvalidIntents,
+            4014 => Self::DisallowedIntents,
+            _ => return Err(value),
+        };
+
+        Ok(code)
+    }
+}
+
+impl From<CloseCode> for u16 {
+    fn from(value: CloseCode) -> Self {
+        value.get()
+    }
+}
+
+impl Display for CloseCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let description = match self {
+            Self::UnknownError => "Unknown error",
+            Self::UnknownOpcode => "Unknown opcode",
+            Self::DecodeError => "Decode error",
+            Self::NotAuthenticated => "Not authenticated",
+            Self::AuthenticationFailed => "Authentication failed",
+            Self::AlreadyAuthenticated => "Already authenticated",
+            Self::InvalidSeq => "Invalid seq",
+            Self::RateLimited => "Rate limited",
+            Self::SessionTimedOut => "Session timed out",
+            Self::InvalidShard => "Invalid shard",
+            Self::ShardingRequired => "Sharding required",
+            Self::InvalidApiVersion => "Invalid API version",
+            Self::InvalidIntents => "Invalid intent(s)",
+            Self::DisallowedIntents => "Disallowed intent(s)",
+        };
+
+        write!(f, "{} ({description})", self.get())
+    }
+}
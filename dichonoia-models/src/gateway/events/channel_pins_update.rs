@@ -0,0 +1,20 @@
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+
+use crate::id::{ChannelId, GuildId};
+
+/// Sent when a message is pinned or unpinned in a text channel. This is not sent when a
+/// pinned message is deleted.
+///
+/// <https://discord.com/developers/docs/events/gateway-events#channel-pins-update>
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChannelPinsUpdate {
+    /// ID of the guild
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub guild_id: Option<GuildId>,
+    /// ID of the channel
+    pub channel_id: ChannelId,
+    /// Time at which the most recent pinned message was pinned
+    #[serde(default, with = "time::serde::rfc3339::option")]
+    pub last_pin_timestamp: Option<OffsetDateTime>,
+}
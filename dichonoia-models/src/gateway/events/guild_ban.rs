@@ -0,0 +1,17 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{id::GuildId, user::User};
+
+/// Sent when a user is banned from a guild (Guild Ban Add), or unbanned from a guild
+/// (Guild Ban Remove).
+///
+/// <https://discord.com/developers/docs/events/gateway-events#guild-ban-add>
+///
+/// <https://discord.com/developers/docs/events/gateway-events#guild-ban-remove>
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GuildBan {
+    /// ID of the guild
+    pub guild_id: GuildId,
+    /// User who was banned or unbanned
+    pub user: User,
+}
@@ -3,7 +3,15 @@ mod parse;
 use serde::{Deserialize, Serialize};
 use time::OffsetDateTime;
 
-use crate::{guild::Guild, id::GuildId};
+use crate::{
+    channel::Channel,
+    gateway::presence::Presence,
+    guild::{Guild, member::Member, scheduled_event::GuildScheduledEvent},
+    id::GuildId,
+    soundboard::SoundboardSound,
+    stage_instance::StageInstance,
+    voice::VoiceState,
+};
 
 /// Lazy-load for unavailable guild, guild became available, or user joined a new guild.
 ///
@@ -19,11 +27,11 @@ pub enum GuildCreate {
     /// [guild]: crate::guild::Guild
     Available {
         /// Guild object.
-        guild: Guild,
+        guild: Box<Guild>,
         /// Source of the event.
         source: GuildCreateSource,
         /// Extra guild create event data.
-        extra_data: GuildCreateExtraData,
+        extra_data: Box<GuildCreateExtraData>,
     },
     /// A guild ID of the unavailable guild.
     Unavailable(GuildId),
@@ -66,8 +74,29 @@ pub enum GuildCreateSource {
 pub struct GuildCreateExtraData {
     /// When this guild was joined at
     #[serde(with = "time::serde::rfc3339")]
-    joined_at: OffsetDateTime,
-    // TODO: add other fields
+    pub joined_at: OffsetDateTime,
+    /// `true` if this is considered a large guild
+    pub large: bool,
+    /// Total number of members in this guild
+    pub member_count: u32,
+    /// States of members currently in voice channels; lacks the `guild_id` key
+    pub voice_states: Vec<VoiceState>,
+    /// Users in the guild
+    pub members: Vec<Member>,
+    /// Channels in the guild
+    pub channels: Vec<Channel>,
+    /// All active threads in the guild that current user has permission to view
+    pub threads: Vec<Channel>,
+    /// Presences of the members in the guild, will only include non-offline members if
+    /// the size is greater than `large_threshold`
+    pub presences: Vec<Presence>,
+    /// Stage instances in the guild
+    pub stage_instances: Vec<StageInstance>,
+    /// Scheduled events in the guild
+    pub guild_scheduled_events: Vec<GuildScheduledEvent>,
+    /// Soundboard sounds in the guild
+    #[serde(default)]
+    pub soundboard_sounds: Vec<SoundboardSound>,
 }
 
 impl GuildCreate {
@@ -17,21 +17,22 @@
 //! Despite the overlap in the guild ID field, if all guild fields and only its ID are
 //! flattened together, the ID will be over-counted by 1: first in from the guild, and
 //! separately again to represent scenario #3. To avoid over-counting, A tagged union
-//! can be used to represent the disjointure.
+//! can be used to represent the disjointure, whose variant is picked from the
+//! `unavailable` field: only `true` means the guild is unavailable.
 //!
 //! [^1]: <https://discord.com/developers/docs/resources/guild#unavailable-guild-object>
 
-use serde::{Deserialize, Deserializer, Serialize, Serializer};
-
 use crate::{
+    content::Content,
     gateway::events::{
         GuildCreate,
         guild_create::{GuildCreateExtraData, GuildCreateSource},
     },
     guild::{Guild, unavailable::UnavailableGuild},
 };
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize)]
 #[serde(untagged)]
 enum RawGuildCreate<G, M> {
     Available(AvailableGuildCreate<G, M>),
@@ -77,34 +78,30 @@ impl<'de> Deserialize<'de> for GuildCreate {
     where
         D: Deserializer<'de>,
     {
-        let value = RawGuildCreate::<Guild, GuildCreateExtraData>::deserialize(deserializer)?;
+        let content = Content::deserialize(deserializer)?;
+        let unavailable = content.field::<Option<bool>, _>("unavailable")?;
 
-        let obj = match value {
-            RawGuildCreate::Available(AvailableGuildCreate {
-                unavailable,
-                guild,
-                metadata,
-            }) => {
-                let source = match unavailable {
-                    None => GuildCreateSource::Joined,
-                    Some(false) => GuildCreateSource::BecameAvailable,
-                    Some(true) => {
-                        return Err(serde::de::Error::custom(
-                            "`unavailable` must be absent or false for an available guild",
-                        ));
-                    }
-                };
-                Self::Available {
-                    guild,
-                    source,
-                    extra_data: metadata,
-                }
-            }
-            RawGuildCreate::Unavailable(unavailable_guild) => {
-                Self::Unavailable(unavailable_guild.into())
+        let source = match unavailable.flatten() {
+            None => GuildCreateSource::Joined,
+            Some(false) => GuildCreateSource::BecameAvailable,
+            Some(true) => {
+                return content
+                    .deserialize_into::<UnavailableGuild, _>()
+                    .map(|guild| Self::Unavailable(guild.into()));
             }
         };
 
-        Ok(obj)
+        let AvailableGuildCreate {
+            unavailable: _,
+            guild,
+            metadata,
+        } = content
+            .deserialize_into::<AvailableGuildCreate<Box<Guild>, Box<GuildCreateExtraData>>, _>()?;
+
+        Ok(Self::Available {
+            guild,
+            source,
+            extra_data: metadata,
+        })
     }
 }
@@ -1,4 +1,76 @@
+//! Internally, Discord's payload format for the guild delete event is an
+//! *unavailable guild*[^1] object:
+//! ```json
+//! { "id": <string>, "unavailable": <bool> }
+//! ```
+//! where `unavailable` is `true` during an outage, and absent when the user was removed
+//! from the guild. This is normalised to a [`GuildDeleteReason`].
+//!
+//! [^1]: <https://discord.com/developers/docs/resources/guild#unavailable-guild-object>
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::id::GuildId;
+
+/// Guild became or was already unavailable due to an outage, or the user left or was
+/// removed from a guild.
+///
 /// <https://discord.com/developers/docs/events/gateway-events#guild-delete>
+#[derive(Debug, Clone, Copy)]
 pub struct GuildDelete {
-    // TODO: add other fields
+    /// ID of the guild
+    pub id: GuildId,
+    /// Why the guild was deleted.
+    pub reason: GuildDeleteReason,
+}
+
+/// Reason of the Guild Delete event.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GuildDeleteReason {
+    /// The guild became unavailable due to an outage. A [Guild Create] event will be
+    /// sent once it becomes available again.
+    ///
+    /// [Guild Create]: crate::gateway::events::guild_create::GuildCreate
+    Unavailable,
+    /// The user left or was removed from the guild.
+    Removed,
+}
+
+#[derive(Serialize, Deserialize)]
+struct RawGuildDelete {
+    id: GuildId,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    unavailable: Option<bool>,
+}
+
+impl Serialize for GuildDelete {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        RawGuildDelete {
+            id: self.id,
+            unavailable: match self.reason {
+                GuildDeleteReason::Unavailable => Some(true),
+                GuildDeleteReason::Removed => None,
+            },
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for GuildDelete {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let raw = RawGuildDelete::deserialize(deserializer)?;
+        let reason = if raw.unavailable == Some(true) {
+            GuildDeleteReason::Unavailable
+        } else {
+            GuildDeleteReason::Removed
+        };
+
+        Ok(Self { id: raw.id, reason })
+    }
 }
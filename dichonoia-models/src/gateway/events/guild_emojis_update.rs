@@ -0,0 +1,14 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{emoji::Emoji, id::GuildId};
+
+/// Sent when a guild's emojis have been updated.
+///
+/// <https://discord.com/developers/docs/events/gateway-events#guild-emojis-update>
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GuildEmojisUpdate {
+    /// ID of the guild
+    pub guild_id: GuildId,
+    /// Array of emojis
+    pub emojis: Vec<Emoji>,
+}
@@ -0,0 +1,12 @@
+use serde::{Deserialize, Serialize};
+
+use crate::id::GuildId;
+
+/// Sent when a guild integration is updated.
+///
+/// <https://discord.com/developers/docs/events/gateway-events#guild-integrations-update>
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct GuildIntegrationsUpdate {
+    /// ID of the guild whose integrations were updated
+    pub guild_id: GuildId,
+}
@@ -0,0 +1,29 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{guild::member::Member, id::GuildId, user::User};
+
+/// Sent when a new user joins a guild (Guild Member Add), or when a guild member is
+/// updated (Guild Member Update).
+///
+/// <https://discord.com/developers/docs/events/gateway-events#guild-member-add>
+///
+/// <https://discord.com/developers/docs/events/gateway-events#guild-member-update>
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GuildMember {
+    /// ID of the guild
+    pub guild_id: GuildId,
+    /// Member that joined or was updated
+    #[serde(flatten)]
+    pub member: Member,
+}
+
+/// Sent when a user is removed from a guild (leave/kick/ban).
+///
+/// <https://discord.com/developers/docs/events/gateway-events#guild-member-remove>
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GuildMemberRemove {
+    /// ID of the guild
+    pub guild_id: GuildId,
+    /// User who was removed
+    pub user: User,
+}
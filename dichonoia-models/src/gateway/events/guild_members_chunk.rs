@@ -0,0 +1,44 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    gateway::presence::Presence,
+    guild::member::Member,
+    id::{GuildId, UserId},
+};
+
+/// Sent in response to [Guild Request Members].
+///
+/// You can use the `chunk_index` and `chunk_count` to calculate how many chunks are left
+/// for your request.
+///
+/// <https://discord.com/developers/docs/events/gateway-events#guild-members-chunk>
+///
+/// [Guild Request Members]: crate::gateway::request_guild_members::RequestGuildMembersPayload
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GuildMembersChunk {
+    /// ID of the guild
+    pub guild_id: GuildId,
+    /// Set of guild members
+    pub members: Vec<Member>,
+    /// Chunk index in the expected chunks for this response
+    /// (`0 <= chunk_index < chunk_count`)
+    pub chunk_index: u32,
+    /// Total number of expected chunks for this response
+    pub chunk_count: u32,
+    /// When passing an invalid ID to [Guild Request Members], it will be returned here
+    ///
+    /// [Guild Request Members]: crate::gateway::request_guild_members::RequestGuildMembersPayload
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub not_found: Vec<UserId>,
+    /// Presences of the matched members, if `presences` was set to `true` in the
+    /// [Guild Request Members]
+    ///
+    /// [Guild Request Members]: crate::gateway::request_guild_members::RequestGuildMembersPayload
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub presences: Vec<Presence>,
+    /// Nonce used in the [Guild Members Request]
+    ///
+    /// [Guild Members Request]: crate::gateway::request_guild_members::RequestGuildMembersPayload
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nonce: Option<String>,
+}
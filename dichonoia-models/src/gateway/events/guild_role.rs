@@ -0,0 +1,31 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    guild::Role,
+    id::{GuildId, RoleId},
+};
+
+/// Sent when a guild role is created (Guild Role Create), or updated (Guild Role
+/// Update).
+///
+/// <https://discord.com/developers/docs/events/gateway-events#guild-role-create>
+///
+/// <https://discord.com/developers/docs/events/gateway-events#guild-role-update>
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GuildRole {
+    /// ID of the guild
+    pub guild_id: GuildId,
+    /// Role that was created or updated
+    pub role: Role,
+}
+
+/// Sent when a guild role is deleted.
+///
+/// <https://discord.com/developers/docs/events/gateway-events#guild-role-delete>
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GuildRoleDelete {
+    /// ID of the guild
+    pub guild_id: GuildId,
+    /// ID of the role
+    pub role_id: RoleId,
+}
@@ -0,0 +1,14 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{id::GuildId, sticker::Sticker};
+
+/// Sent when a guild's stickers have been updated.
+///
+/// <https://discord.com/developers/docs/events/gateway-events#guild-stickers-update>
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GuildStickersUpdate {
+    /// ID of the guild
+    pub guild_id: GuildId,
+    /// Array of stickers
+    pub stickers: Vec<Sticker>,
+}
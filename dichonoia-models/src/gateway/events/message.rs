@@ -0,0 +1,31 @@
+use serde::{Deserialize, Serialize};
+
+use crate::id::{ChannelId, GuildId, MessageId};
+
+/// Sent when a message is deleted.
+///
+/// <https://discord.com/developers/docs/events/gateway-events#message-delete>
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MessageDelete {
+    /// ID of the message
+    pub id: MessageId,
+    /// ID of the channel
+    pub channel_id: ChannelId,
+    /// ID of the guild
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub guild_id: Option<GuildId>,
+}
+
+/// Sent when multiple messages are deleted at once.
+///
+/// <https://discord.com/developers/docs/events/gateway-events#message-delete-bulk>
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MessageDeleteBulk {
+    /// IDs of the messages
+    pub ids: Vec<MessageId>,
+    /// ID of the channel
+    pub channel_id: ChannelId,
+    /// ID of the guild
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub guild_id: Option<GuildId>,
+}
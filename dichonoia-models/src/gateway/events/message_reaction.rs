@@ -0,0 +1,94 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    emoji::Emoji,
+    guild::member::Member,
+    id::{ChannelId, GuildId, MessageId, UserId},
+    message::reaction::ReactionType,
+};
+
+/// Sent when a user adds a reaction to a message.
+///
+/// <https://discord.com/developers/docs/events/gateway-events#message-reaction-add>
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MessageReactionAdd {
+    /// ID of the user
+    pub user_id: UserId,
+    /// ID of the channel
+    pub channel_id: ChannelId,
+    /// ID of the message
+    pub message_id: MessageId,
+    /// ID of the guild
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub guild_id: Option<GuildId>,
+    /// Member who reacted if this happened in a guild
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub member: Option<Member>,
+    /// Emoji used to react, partial for custom emojis
+    pub emoji: Emoji,
+    /// ID of the user who authored the message which was reacted to
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub message_author_id: Option<UserId>,
+    /// `true` if this is a super-reaction
+    pub burst: bool,
+    /// Colors used for super-reaction animation in "#rrggbb" format
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub burst_colors: Vec<String>,
+    /// [Type of reaction](ReactionType)
+    #[serde(rename = "type")]
+    pub kind: ReactionType,
+}
+
+/// Sent when a user removes a reaction from a message.
+///
+/// <https://discord.com/developers/docs/events/gateway-events#message-reaction-remove>
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MessageReactionRemove {
+    /// ID of the user
+    pub user_id: UserId,
+    /// ID of the channel
+    pub channel_id: ChannelId,
+    /// ID of the message
+    pub message_id: MessageId,
+    /// ID of the guild
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub guild_id: Option<GuildId>,
+    /// Emoji used to react, partial for custom emojis
+    pub emoji: Emoji,
+    /// `true` if this was a super-reaction
+    pub burst: bool,
+    /// [Type of reaction](ReactionType)
+    #[serde(rename = "type")]
+    pub kind: ReactionType,
+}
+
+/// Sent when a user explicitly removes all reactions from a message.
+///
+/// <https://discord.com/developers/docs/events/gateway-events#message-reaction-remove-all>
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MessageReactionRemoveAll {
+    /// ID of the channel
+    pub channel_id: ChannelId,
+    /// ID of the message
+    pub message_id: MessageId,
+    /// ID of the guild
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub guild_id: Option<GuildId>,
+}
+
+/// Sent when a bot removes all instances of a given emoji from the reactions of a
+/// message.
+///
+/// <https://discord.com/developers/docs/events/gateway-events#message-reaction-remove-emoji>
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MessageReactionRemoveEmoji {
+    /// ID of the channel
+    pub channel_id: ChannelId,
+    /// ID of the guild
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub guild_id: Option<GuildId>,
+    /// ID of the message
+    pub message_id: MessageId,
+    /// Emoji that was removed
+    pub emoji: Emoji,
+}
@@ -1,6 +1,181 @@
+pub mod channel_pins_update;
+pub mod guild_ban;
 pub mod guild_create;
 pub mod guild_delete;
+pub mod guild_emojis_update;
+pub mod guild_integrations_update;
+pub mod guild_member;
+pub mod guild_members_chunk;
+pub mod guild_role;
+pub mod guild_stickers_update;
+pub mod message;
+pub mod message_reaction;
 pub mod ready;
+pub mod soundboard;
+pub mod voice_server_update;
 
+pub use channel_pins_update::ChannelPinsUpdate;
+pub use guild_ban::GuildBan;
 pub use guild_create::GuildCreate;
+pub use guild_delete::GuildDelete;
+pub use guild_emojis_update::GuildEmojisUpdate;
+pub use guild_integrations_update::GuildIntegrationsUpdate;
+pub use guild_member::{GuildMember, GuildMemberRemove};
+pub use guild_members_chunk::GuildMembersChunk;
+pub use guild_role::{GuildRole, GuildRoleDelete};
+pub use guild_stickers_update::GuildStickersUpdate;
+pub use message::{MessageDelete, MessageDeleteBulk};
+pub use message_reaction::{
+    MessageReactionAdd, MessageReactionRemove, MessageReactionRemoveAll, MessageReactionRemoveEmoji,
+};
 pub use ready::Ready;
+pub use soundboard::{GuildSoundboardSoundDelete, SoundboardSounds};
+pub use voice_server_update::VoiceServerUpdate;
+
+use serde::de::IntoDeserializer;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::{Error as JsonError, Value};
+
+use crate::{
+    channel::Channel, content::Content, gateway::presence::Presence, guild::Guild,
+    message::Message, soundboard::SoundboardSound, voice::VoiceState,
+};
+
+/// Defines [`GatewayEvent`] from the name of each event and the type of its data, so that
+/// both always stay in sync.
+macro_rules! gateway_events {
+    ( $( $variant:ident($ty:ty) = $name:literal ),+ $(,)? ) => {
+        /// Event dispatched by the gateway.
+        ///
+        /// <https://discord.com/developers/docs/events/gateway-events#receive-events>
+        #[derive(Debug, Clone)]
+        pub enum GatewayEvent {
+            $( $variant($ty), )+
+            /// Event that is not modeled by this library, with its raw data.
+            Unknown {
+                /// Name of the event
+                name: String,
+                /// Raw data of the event
+                data: Value,
+            },
+        }
+
+        impl GatewayEvent {
+            /// Name of the event, as sent in the `t` field of the dispatch payload.
+            #[must_use]
+            pub fn name(&self) -> &str {
+                match self {
+                    $( Self::$variant(_) => $name, )+
+                    Self::Unknown { name, .. } => name,
+                }
+            }
+
+            /// Deserializes the `data` of the event called `name`, falling back to
+            /// [`GatewayEvent::Unknown`] if the event is not modeled.
+            ///
+            /// # Errors
+            ///
+            /// Returns an error if the event is modeled, but `data` does not match its
+            /// model.
+            pub fn from_json(name: &str, data: &Value) -> Result<Self, JsonError> {
+                Self::deserialize_named(name, data)
+            }
+
+            /// Deserializes the data of the event called `name` from `deserializer`, falling
+            /// back to [`GatewayEvent::Unknown`] if the event is not modeled.
+            ///
+            /// Unlike [`GatewayEvent::from_json`], this works with any self-describing
+            /// format, and only goes through a [`Value`] for events that are not modeled.
+            ///
+            /// # Errors
+            ///
+            /// Returns an error if the event is modeled, but its data does not match its
+            /// model.
+            pub fn deserialize_named<'de, D>(name: &str, deserializer: D) -> Result<Self, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                match name {
+                    $( $name => <$ty>::deserialize(deserializer).map(Self::$variant), )+
+                    _ => Ok(Self::Unknown {
+                        name: String::from(name),
+                        data: Value::deserialize(deserializer)?,
+                    }),
+                }
+            }
+        }
+
+        impl Serialize for GatewayEvent {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                match self {
+                    $( Self::$variant(data) => TaggedEvent { name: $name, data }.serialize(serializer), )+
+                    Self::Unknown { name, data } => TaggedEvent { name, data }.serialize(serializer),
+                }
+            }
+        }
+    };
+}
+
+gateway_events! {
+    Ready(Box<Ready>) = "READY",
+    ChannelCreate(Channel) = "CHANNEL_CREATE",
+    ChannelUpdate(Channel) = "CHANNEL_UPDATE",
+    ChannelDelete(Channel) = "CHANNEL_DELETE",
+    ChannelPinsUpdate(ChannelPinsUpdate) = "CHANNEL_PINS_UPDATE",
+    GuildCreate(GuildCreate) = "GUILD_CREATE",
+    GuildUpdate(Box<Guild>) = "GUILD_UPDATE",
+    GuildDelete(GuildDelete) = "GUILD_DELETE",
+    GuildBanAdd(Box<GuildBan>) = "GUILD_BAN_ADD",
+    GuildBanRemove(Box<GuildBan>) = "GUILD_BAN_REMOVE",
+    GuildEmojisUpdate(GuildEmojisUpdate) = "GUILD_EMOJIS_UPDATE",
+    GuildStickersUpdate(GuildStickersUpdate) = "GUILD_STICKERS_UPDATE",
+    GuildIntegrationsUpdate(GuildIntegrationsUpdate) = "GUILD_INTEGRATIONS_UPDATE",
+    GuildMemberAdd(Box<GuildMember>) = "GUILD_MEMBER_ADD",
+    GuildMemberUpdate(Box<GuildMember>) = "GUILD_MEMBER_UPDATE",
+    GuildMemberRemove(Box<GuildMemberRemove>) = "GUILD_MEMBER_REMOVE",
+    GuildMembersChunk(GuildMembersChunk) = "GUILD_MEMBERS_CHUNK",
+    GuildRoleCreate(GuildRole) = "GUILD_ROLE_CREATE",
+    GuildRoleUpdate(GuildRole) = "GUILD_ROLE_UPDATE",
+    GuildRoleDelete(GuildRoleDelete) = "GUILD_ROLE_DELETE",
+    MessageCreate(Box<Message>) = "MESSAGE_CREATE",
+    MessageUpdate(Box<Message>) = "MESSAGE_UPDATE",
+    MessageDelete(MessageDelete) = "MESSAGE_DELETE",
+    MessageDeleteBulk(MessageDeleteBulk) = "MESSAGE_DELETE_BULK",
+    MessageReactionAdd(Box<MessageReactionAdd>) = "MESSAGE_REACTION_ADD",
+    MessageReactionRemove(MessageReactionRemove) = "MESSAGE_REACTION_REMOVE",
+    MessageReactionRemoveAll(MessageReactionRemoveAll) = "MESSAGE_REACTION_REMOVE_ALL",
+    MessageReactionRemoveEmoji(MessageReactionRemoveEmoji) = "MESSAGE_REACTION_REMOVE_EMOJI",
+    PresenceUpdate(Presence) = "PRESENCE_UPDATE",
+    VoiceStateUpdate(Box<VoiceState>) = "VOICE_STATE_UPDATE",
+    VoiceServerUpdate(VoiceServerUpdate) = "VOICE_SERVER_UPDATE",
+    SoundboardSounds(SoundboardSounds) = "SOUNDBOARD_SOUNDS",
+    GuildSoundboardSoundCreate(Box<SoundboardSound>) = "GUILD_SOUNDBOARD_SOUND_CREATE",
+    GuildSoundboardSoundUpdate(Box<SoundboardSound>) = "GUILD_SOUNDBOARD_SOUND_UPDATE",
+    GuildSoundboardSoundDelete(GuildSoundboardSoundDelete) = "GUILD_SOUNDBOARD_SOUND_DELETE",
+    GuildSoundboardSoundsUpdate(SoundboardSounds) = "GUILD_SOUNDBOARD_SOUNDS_UPDATE",
+}
+
+/// Event as it is flattened into a dispatch payload.
+#[derive(Serialize)]
+struct TaggedEvent<'a, T> {
+    #[serde(rename = "t")]
+    name: &'a str,
+    #[serde(rename = "d")]
+    data: &'a T,
+}
+
+impl<'de> Deserialize<'de> for GatewayEvent {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let mut content = Content::deserialize(deserializer)?;
+        let name: String = content.required_field("t")?;
+        let data = content.take_field("d").unwrap_or(Content::Unit);
+
+        Self::deserialize_named(&name, data.into_deserializer())
+    }
+}
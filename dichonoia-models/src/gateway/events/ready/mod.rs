@@ -4,7 +4,7 @@ pub mod shard_info;
 use crate::{
     gateway::events::ready::{partial_application::PartialApplication, shard_info::ShardInfo},
     id::GuildId,
-    user::User,
+    user::CurrentUser,
 };
 use serde::{Deserialize, Serialize};
 
@@ -28,7 +28,7 @@ pub struct Ready {
     #[serde(rename = "v")]
     pub version: u8,
     /// Information about the user including email
-    pub user: User,
+    pub user: CurrentUser,
     /// Guilds the user is in
     #[serde(with = "crate::guild::unavailable")]
     pub guilds: Vec<GuildId>,
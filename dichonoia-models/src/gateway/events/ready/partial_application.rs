@@ -1,5 +1,5 @@
 use bitflags::bitflags;
-use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde::{Deserialize, Serialize};
 
 use crate::id::ApplicationId;
 
@@ -57,21 +57,4 @@ bitflags! {
     }
 }
 
-impl Serialize for ApplicationFlags {
-    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-    where
-        S: Serializer,
-    {
-        serializer.serialize_u32(self.bits())
-    }
-}
-
-impl<'de> Deserialize<'de> for ApplicationFlags {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
-    where
-        D: Deserializer<'de>,
-    {
-        Self::from_bits(<_>::deserialize(deserializer)?)
-            .ok_or_else(|| serde::de::Error::custom("invalid application flags"))
-    }
-}
+bitflags_serde!(ApplicationFlags);
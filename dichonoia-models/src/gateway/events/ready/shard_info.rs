@@ -160,7 +160,7 @@ use std::num::NonZeroU16;
 ///
 /// [Identify]: crate::gateway::IdentifyPayload
 /// [`max_concurrency`]: crate::gateway::SessionStartLimit
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct ShardInfo {
     /// Shard ID
     pub id: u16,
@@ -0,0 +1,31 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    id::{GuildId, SoundId},
+    soundboard::SoundboardSound,
+};
+
+/// Sent in response to [Request Soundboard Sounds], and when multiple guild soundboard
+/// sounds are updated.
+///
+/// <https://discord.com/developers/docs/events/gateway-events#soundboard-sounds>
+///
+/// [Request Soundboard Sounds]: crate::gateway::request_soundboard_sounds::RequestSoundboardSoundsPayload
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SoundboardSounds {
+    /// The guild's soundboard sounds
+    pub soundboard_sounds: Vec<SoundboardSound>,
+    /// ID of the guild
+    pub guild_id: GuildId,
+}
+
+/// Sent when a guild soundboard sound is deleted.
+///
+/// <https://discord.com/developers/docs/events/gateway-events#guild-soundboard-sound-delete>
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct GuildSoundboardSoundDelete {
+    /// ID of the sound that was deleted
+    pub sound_id: SoundId,
+    /// ID of the guild the sound was in
+    pub guild_id: GuildId,
+}
@@ -0,0 +1,24 @@
+use serde::{Deserialize, Serialize};
+
+use crate::id::GuildId;
+
+/// Sent when a guild's voice server is updated. This is sent when initially connecting to
+/// voice, and when the current voice instance fails over to a new server.
+///
+/// <div class="warning">
+///
+/// A `None` endpoint means that the voice server allocated has gone away and is trying to
+/// be reallocated. You should attempt to disconnect from the currently connected voice
+/// server, and not attempt to reconnect until a new voice server is allocated.
+/// </div>
+///
+/// <https://discord.com/developers/docs/events/gateway-events#voice-server-update>
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VoiceServerUpdate {
+    /// Voice connection token
+    pub token: String,
+    /// Guild this voice server update is for
+    pub guild_id: GuildId,
+    /// Voice server host
+    pub endpoint: Option<String>,
+}
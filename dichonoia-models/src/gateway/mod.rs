@@ -1,65 +1,114 @@
+pub mod close_code;
 pub mod events;
+pub mod presence;
+pub mod request_guild_members;
+pub mod request_soundboard_sounds;
+pub mod voice_state_update;
 
-use crate::gateway::events::{GuildCreate, Ready};
+use crate::content::Content;
+use crate::gateway::events::ready::shard_info::ShardInfo;
+use crate::gateway::presence::PresenceUpdatePayload;
+use crate::gateway::request_guild_members::RequestGuildMembersPayload;
+use crate::gateway::request_soundboard_sounds::RequestSoundboardSoundsPayload;
+use crate::gateway::voice_state_update::VoiceStateUpdatePayload;
 use bitflags::bitflags;
+pub use close_code::CloseCode;
+pub use events::GatewayEvent;
 use serde::Deserializer;
 use serde::de::Error as DeError;
-use serde::de::Unexpected;
+use serde::de::{IgnoredAny, IntoDeserializer, MapAccess, Unexpected, Visitor};
 use serde::{Deserialize, Serialize, Serializer};
 use serde_json::Error as JsonError;
 use serde_json::Value;
+use std::fmt::{self, Display};
+use std::num::NonZeroU16;
 
 #[derive(Debug, Clone)]
 pub enum GatewayPayload {
-    Dispatch(DispatchPayload), // 0
-    Heartbeat,                 // 1
-    Identify(IdentifyPayload), // 2
-    PresenceUpdate,            // 3
-    VoiceStateUpdate,          // 4
-    Resume,                    // 6
-    Reconnect,                 // 7
-    RequestGuildMembers,       // 8
-    InvalidSession,            // 9
-    Hello(HelloPayload),       // 10
-    HeartBeatACK,              // 11
-    RequestSoundboardSounds,   // 31
+    Dispatch(DispatchPayload),                               // 0
+    Heartbeat(Option<i64>),                                  // 1
+    Identify(IdentifyPayload),                               // 2
+    PresenceUpdate(PresenceUpdatePayload),                   // 3
+    VoiceStateUpdate(VoiceStateUpdatePayload),               // 4
+    Resume(ResumePayload),                                   // 6
+    Reconnect,                                               // 7
+    RequestGuildMembers(RequestGuildMembersPayload),         // 8
+    InvalidSession(bool),                                    // 9
+    Hello(HelloPayload),                                     // 10
+    HeartBeatACK,                                            // 11
+    RequestSoundboardSounds(RequestSoundboardSoundsPayload), // 31
 }
 
 impl GatewayPayload {
+    /// Deserializes a payload from a [`Value`] with [`GatewayPayload::deserialize_with`].
+    ///
     /// # Errors
     ///
-    /// ...
-    pub fn from_json(value: Value) -> Result<Self, JsonError> {
-        let op_val = value
-            .get("op")
-            .ok_or_else(|| JsonError::missing_field("op"))?;
-        let op = i32::deserialize(op_val)?;
-
-        match op {
-            0 => Ok(Self::Dispatch(DispatchPayload::deserialize(value)?)),
-            1 => Ok(Self::Heartbeat),
-            2 => Ok(Self::Identify(Self::deserialize_data(&value)?)),
-            3 => Ok(Self::PresenceUpdate),
-            4 => Ok(Self::VoiceStateUpdate),
-            6 => Ok(Self::Resume),
-            7 => Ok(Self::Reconnect),
-            8 => Ok(Self::RequestGuildMembers),
-            9 => Ok(Self::InvalidSession),
-            10 => Ok(Self::Hello(Self::deserialize_data(&value)?)),
-            11 => Ok(Self::HeartBeatACK),
-            31 => Ok(Self::RequestSoundboardSounds),
-            _ => Err(JsonError::invalid_value(
-                Unexpected::Signed(i64::from(op)),
-                &"Not a valid opcode",
-            )),
-        }
+    /// See [`GatewayPayload::deserialize_with`].
+    pub fn from_json(value: &Value) -> Result<Self, PayloadError> {
+        Self::deserialize_with(|| value)
     }
 
-    fn deserialize_data<'de, D: Deserialize<'de>>(value: &'de Value) -> Result<D, JsonError> {
-        let data = value
-            .get("d")
-            .ok_or_else(|| JsonError::missing_field("d"))?;
-        D::deserialize(data)
+    /// Deserializes a JSON message with [`GatewayPayload::deserialize_with`].
+    ///
+    /// # Errors
+    ///
+    /// See [`GatewayPayload::deserialize_with`].
+    #[expect(
+        clippy::missing_panics_doc,
+        reason = "`deserialize_with` makes at most three passes"
+    )]
+    pub fn from_slice(json: &[u8]) -> Result<Self, PayloadError> {
+        // A JSON deserializer can only be used by reference, so one is created for each
+        // pass up front.
+        let mut passes = [(); 3].map(|()| serde_json::Deserializer::from_slice(json));
+        let mut passes = passes.iter_mut();
+        Self::deserialize_with(|| passes.next().expect("at most three passes"))
+    }
+
+    /// Deserializes a payload from any self-describing format, such as JSON or ETF,
+    /// straight into the model of its data rather than through a [`Value`].
+    ///
+    /// The model of the data depends on the opcode and event name, which may come after
+    /// the data, so `deserializer` is called once to read them and once more to
+    /// deserialize the payload. It is only called a third time to keep the raw data of an
+    /// event that does not match its model. It should therefore be cheap to create, such
+    /// as a deserializer over a byte slice.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PayloadError::Invalid`] if the payload itself is invalid, and
+    /// [`PayloadError::Event`] if it is a valid dispatch whose event does not match its
+    /// model.
+    pub fn deserialize_with<'de, D, F>(mut deserializer: F) -> Result<Self, PayloadError<D::Error>>
+    where
+        D: Deserializer<'de>,
+        D::Error: std::error::Error + Send + Sync + 'static,
+        F: FnMut() -> D,
+    {
+        let header = PayloadHeader::deserialize(deserializer()).map_err(PayloadError::Invalid)?;
+
+        let source = match deserializer().deserialize_map(PayloadVisitor { header: &header }) {
+            Ok(payload) => return Ok(payload),
+            Err(source) => source,
+        };
+
+        match (header.op, header.name, header.sequence) {
+            // The event is the only invalid part if its raw data can be deserialized.
+            (0, Some(name), Some(sequence)) => {
+                let data = deserializer()
+                    .deserialize_map(RawDataVisitor)
+                    .map_err(PayloadError::Invalid)?;
+
+                Err(PayloadError::Event(EventError {
+                    name,
+                    data,
+                    sequence,
+                    source: Box::new(source),
+                }))
+            }
+            _ => Err(PayloadError::Invalid(source)),
+        }
     }
 
     /// # Panics
@@ -76,19 +125,24 @@ impl GatewayPayload {
             serde_json::to_value(v)?
         } else {
             let data = match self {
-                Self::Identify(v) => serde_json::to_value(v)?,
-                Self::Hello(v) => serde_json::to_value(v)?,
-                _ => Value::Null,
+                Self::Heartbeat(v) => Some(serde_json::to_value(v)?),
+                Self::Identify(v) => Some(serde_json::to_value(v)?),
+                Self::PresenceUpdate(v) => Some(serde_json::to_value(v)?),
+                Self::VoiceStateUpdate(v) => Some(serde_json::to_value(v)?),
+                Self::Resume(v) => Some(serde_json::to_value(v)?),
+                Self::RequestGuildMembers(v) => Some(serde_json::to_value(v)?),
+                Self::InvalidSession(v) => Some(serde_json::to_value(v)?),
+                Self::RequestSoundboardSounds(v) => Some(serde_json::to_value(v)?),
+                Self::Hello(v) => Some(serde_json::to_value(v)?),
+                _ => None,
             };
 
-            if matches!(data, Value::Object(_)) {
-                let mut map = serde_json::Map::with_capacity(2);
+            let mut map = serde_json::Map::with_capacity(2);
+            if let Some(data) = data {
                 map.insert(String::from("d"), data);
-
-                Value::Object(map)
-            } else {
-                Value::Object(serde_json::Map::with_capacity(1))
             }
+
+            Value::Object(map)
         };
 
         if let Value::Object(obj) = &mut value {
@@ -104,29 +158,22 @@ impl GatewayPayload {
     pub const fn op(&self) -> i32 {
         match self {
             Self::Dispatch(_) => 0,
-            Self::Heartbeat => 1,
+            Self::Heartbeat(_) => 1,
             Self::Identify(_) => 2,
-            Self::PresenceUpdate => 3,
-            Self::VoiceStateUpdate => 4,
-            Self::Resume => 6,
+            Self::PresenceUpdate(_) => 3,
+            Self::VoiceStateUpdate(_) => 4,
+            Self::Resume(_) => 6,
             Self::Reconnect => 7,
-            Self::RequestGuildMembers => 8,
-            Self::InvalidSession => 9,
+            Self::RequestGuildMembers(_) => 8,
+            Self::InvalidSession(_) => 9,
             Self::Hello(_) => 10,
             Self::HeartBeatACK => 11,
-            Self::RequestSoundboardSounds => 31,
+            Self::RequestSoundboardSounds(_) => 31,
         }
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(tag = "t", content = "d", rename_all = "SCREAMING_SNAKE_CASE")]
-pub enum GatewayEvent {
-    Ready(Ready),
-    GuildCreate(GuildCreate),
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize)]
 pub struct DispatchPayload {
     #[serde(flatten)]
     pub data: Option<GatewayEvent>,
@@ -134,9 +181,249 @@ pub struct DispatchPayload {
     pub sequence: i64,
 }
 
+impl<'de> Deserialize<'de> for DispatchPayload {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let mut content = Content::deserialize(deserializer)?;
+        let sequence = content.required_field("s")?;
+        let data = match content.field::<Option<String>, _>("t")?.flatten() {
+            Some(name) => {
+                let data = content.take_field("d").unwrap_or(Content::Unit);
+                Some(GatewayEvent::deserialize_named(
+                    &name,
+                    data.into_deserializer(),
+                )?)
+            }
+            None => None,
+        };
+
+        Ok(Self { data, sequence })
+    }
+}
+
+/// Error returned by [`GatewayPayload::deserialize_with`], with the error type `E` of
+/// the format the payload is deserialized from.
+#[derive(Debug)]
+pub enum PayloadError<E = JsonError> {
+    /// The payload itself is invalid.
+    Invalid(E),
+    /// The payload is a valid dispatch, but its event could not be deserialized.
+    Event(EventError),
+}
+
+impl<E: Display> Display for PayloadError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Invalid(err) => Display::fmt(err, f),
+            Self::Event(err) => Display::fmt(err, f),
+        }
+    }
+}
+
+impl<E: std::error::Error + 'static> std::error::Error for PayloadError<E> {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Invalid(err) => Some(err),
+            Self::Event(err) => Some(err),
+        }
+    }
+}
+
+impl From<JsonError> for PayloadError {
+    fn from(value: JsonError) -> Self {
+        Self::Invalid(value)
+    }
+}
+
+/// A dispatched event is modeled, but its data does not match its model.
+#[derive(Debug)]
+pub struct EventError {
+    /// Name of the event
+    pub name: String,
+    /// Raw data of the event
+    pub data: Value,
+    /// Sequence number of the dispatch payload, which must still be acknowledged
+    pub sequence: i64,
+    /// Why the data does not match the model, as reported by the format it was received
+    /// in
+    pub source: Box<dyn std::error::Error + Send + Sync>,
+}
+
+impl Display for EventError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Failed to deserialize {} event: {}",
+            self.name, self.source
+        )
+    }
+}
+
+impl std::error::Error for EventError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&*self.source)
+    }
+}
+
+/// Fields of a payload that select the model of its data.
+#[derive(Deserialize)]
+struct PayloadHeader {
+    op: i32,
+    #[serde(rename = "t", default)]
+    name: Option<String>,
+    #[serde(rename = "s", default)]
+    sequence: Option<i64>,
+}
+
+impl PayloadHeader {
+    /// Payload with this header and no data, if its opcode does not require any.
+    fn without_data(&self) -> Option<GatewayPayload> {
+        match (self.op, &self.name) {
+            (0, None) => Some(GatewayPayload::Dispatch(DispatchPayload {
+                data: None,
+                sequence: self.sequence?,
+            })),
+            (1, _) => Some(GatewayPayload::Heartbeat(None)),
+            (7, _) => Some(GatewayPayload::Reconnect),
+            (11, _) => Some(GatewayPayload::HeartBeatACK),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(field_identifier)]
+enum PayloadField {
+    #[serde(rename = "d")]
+    Data,
+    #[serde(other)]
+    Other,
+}
+
+/// Deserializes a payload whose data is deserialized into the model selected by
+/// `header`.
+struct PayloadVisitor<'a> {
+    header: &'a PayloadHeader,
+}
+
+impl<'de> Visitor<'de> for PayloadVisitor<'_> {
+    type Value = GatewayPayload;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a gateway payload")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut payload = None;
+        while let Some(field) = map.next_key()? {
+            match field {
+                PayloadField::Data if payload.is_some() => {
+                    return Err(A::Error::duplicate_field("d"));
+                }
+                PayloadField::Data => {
+                    payload = Some(map.next_value_seed(DataSeed {
+                        header: self.header,
+                    })?);
+                }
+                PayloadField::Other => {
+                    map.next_value::<IgnoredAny>()?;
+                }
+            }
+        }
+
+        payload
+            .or_else(|| self.header.without_data())
+            .ok_or_else(|| A::Error::missing_field("d"))
+    }
+}
+
+/// Deserializes the data of a payload into the model selected by `header`.
+struct DataSeed<'a> {
+    header: &'a PayloadHeader,
+}
+
+impl<'de> serde::de::DeserializeSeed<'de> for DataSeed<'_> {
+    type Value = GatewayPayload;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let payload = match self.header.op {
+            0 => {
+                let sequence = self
+                    .header
+                    .sequence
+                    .ok_or_else(|| D::Error::missing_field("s"))?;
+                let data = if let Some(name) = &self.header.name {
+                    Some(GatewayEvent::deserialize_named(name, deserializer)?)
+                } else {
+                    IgnoredAny::deserialize(deserializer)?;
+                    None
+                };
+
+                GatewayPayload::Dispatch(DispatchPayload { data, sequence })
+            }
+            1 => GatewayPayload::Heartbeat(Option::deserialize(deserializer)?),
+            2 => GatewayPayload::Identify(Deserialize::deserialize(deserializer)?),
+            3 => GatewayPayload::PresenceUpdate(Deserialize::deserialize(deserializer)?),
+            4 => GatewayPayload::VoiceStateUpdate(Deserialize::deserialize(deserializer)?),
+            6 => GatewayPayload::Resume(Deserialize::deserialize(deserializer)?),
+            7 => {
+                IgnoredAny::deserialize(deserializer)?;
+                GatewayPayload::Reconnect
+            }
+            8 => GatewayPayload::RequestGuildMembers(Deserialize::deserialize(deserializer)?),
+            9 => GatewayPayload::InvalidSession(Deserialize::deserialize(deserializer)?),
+            10 => GatewayPayload::Hello(Deserialize::deserialize(deserializer)?),
+            11 => {
+                IgnoredAny::deserialize(deserializer)?;
+                GatewayPayload::HeartBeatACK
+            }
+            31 => GatewayPayload::RequestSoundboardSounds(Deserialize::deserialize(deserializer)?),
+            op => {
+                return Err(D::Error::invalid_value(
+                    Unexpected::Signed(i64::from(op)),
+                    &"Not a valid opcode",
+                ));
+            }
+        };
+
+        Ok(payload)
+    }
+}
+
+/// Deserializes only the raw data of a payload.
+struct RawDataVisitor;
+
+impl<'de> Visitor<'de> for RawDataVisitor {
+    type Value = Value;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a gateway payload")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut data = Value::Null;
+        while let Some(field) = map.next_key()? {
+            match field {
+                PayloadField::Data => data = map.next_value()?,
+                PayloadField::Other => {
+                    map.next_value::<IgnoredAny>()?;
+                }
+            }
+        }
+
+        Ok(data)
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct HelloPayload {
-    pub heartbeat_interval: i32,
+    /// Interval (in milliseconds) an app should heartbeat with
+    pub heartbeat_interval: u64,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -145,13 +432,33 @@ pub struct IdentifyPayload {
     pub properties: IdentifyProperties,
     #[serde(default)]
     pub compress: bool,
+    /// Value between 50 and 250: total number of members where the gateway will stop
+    /// sending offline members in the guild member list
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub large_threshold: Option<i32>,
+    pub large_threshold: Option<u8>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub shard: Option<[i32; 2]>,
+    pub shard: Option<ShardInfo>,
+    /// Presence structure for initial presence information
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub presence: Option<PresenceUpdatePayload>,
     pub intents: GatewayIntents,
 }
 
+/// Resume Structure
+///
+/// Used to replay missed events when a disconnected client resumes.
+///
+/// <https://discord.com/developers/docs/events/gateway-events#resume-resume-structure>
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ResumePayload {
+    /// Session token
+    pub token: String,
+    /// Session ID
+    pub session_id: String,
+    /// Last sequence number received
+    pub seq: i64,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct IdentifyProperties {
     pub os: String,
@@ -205,10 +512,16 @@ impl<'de> Deserialize<'de> for GatewayIntents {
     }
 }
 
+/// <https://discord.com/developers/docs/events/gateway#get-gateway-bot-json-response>
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GatewayBot {
+    /// WSS URL that can be used for connecting to the Gateway
     pub url: String,
-    pub shards: i32,
+    /// Recommended number of [shards] to use when connecting
+    ///
+    /// [shards]: crate::gateway::events::ready::shard_info::ShardInfo
+    pub shards: NonZeroU16,
+    /// Information on the current session start limit
     pub session_start_limit: SessionStartLimit,
 }
 
@@ -218,11 +531,11 @@ pub struct GatewayBot {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SessionStartLimit {
     /// Total number of session starts the current user is allowed
-    pub total: i32,
+    pub total: u32,
     /// Remaining number of session starts the current user is allowed
-    pub remaining: i32,
+    pub remaining: u32,
     /// Number of milliseconds after which the limit resets
-    pub reset_after: i64,
+    pub reset_after: u64,
     /// Number of identify requests allowed per 5 seconds
-    pub max_concurrency: i32,
+    pub max_concurrency: NonZeroU16,
 }
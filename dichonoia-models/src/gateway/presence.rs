@@ -0,0 +1,398 @@
+use bitflags::bitflags;
+use serde::{Deserialize, Deserializer, Serialize};
+
+use crate::id::{ApplicationId, EmojiId, GuildId, UserId};
+
+/// Update Presence Structure
+///
+/// Sent by the client to indicate a presence or status update, or as the initial
+/// presence when identifying.
+///
+/// <https://discord.com/developers/docs/events/gateway-events#update-presence-gateway-presence-update-structure>
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PresenceUpdatePayload {
+    /// Unix time (in milliseconds) of when the client went idle, or `None` if the client
+    /// is not idle
+    pub since: Option<u64>,
+    /// User's activities
+    pub activities: Vec<Activity>,
+    /// User's new [status](Status)
+    pub status: Status,
+    /// Whether or not the client is afk
+    pub afk: bool,
+}
+
+/// Presence Update Event Fields
+///
+/// A user's presence is their current state on a guild.
+///
+/// <https://discord.com/developers/docs/events/gateway-events#presence-update-presence-update-event-fields>
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Presence {
+    /// User whose presence is being updated
+    pub user: PresenceUser,
+    /// ID of the guild, missing from the presences sent in [Guild Create]
+    ///
+    /// [Guild Create]: crate::gateway::events::GuildCreate
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub guild_id: Option<GuildId>,
+    /// [Status] of the user
+    pub status: Status,
+    /// User's current activities
+    #[serde(default)]
+    pub activities: Vec<Activity>,
+    /// User's platform-dependent status
+    #[serde(default)]
+    pub client_status: ClientStatus,
+}
+
+/// User of a [`Presence`], of which only the ID is guaranteed to be sent.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct PresenceUser {
+    /// ID of the user
+    pub id: UserId,
+}
+
+/// Client Status Object
+///
+/// Active sessions are indicated with an `online`, `idle`, or `dnd` [status](Status). No
+/// status is sent for inactive sessions.
+///
+/// <https://discord.com/developers/docs/events/gateway-events#client-status-object>
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ClientStatus {
+    /// User's status set for an active desktop (Windows, Linux, Mac) application session
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub desktop: Option<Status>,
+    /// User's status set for an active mobile (iOS, Android) application session
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mobile: Option<Status>,
+    /// User's status set for an active web (browser, bot user) application session
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub web: Option<Status>,
+}
+
+/// Status Types
+///
+/// <https://discord.com/developers/docs/events/gateway-events#update-presence-status-types>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Status {
+    /// Online
+    Online,
+    /// Do Not Disturb
+    Dnd,
+    /// AFK
+    Idle,
+    /// Invisible and shown as offline
+    Invisible,
+    /// Offline
+    Offline,
+}
+
+/// Activity Object
+///
+/// Bots are only able to send `name`, `type`, `state`, and `url`; the other fields are
+/// only set on received activities.
+///
+/// <https://discord.com/developers/docs/events/gateway-events#activity-object>
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Activity {
+    /// Activity's name
+    pub name: String,
+    /// [Activity type](ActivityType)
+    #[serde(rename = "type")]
+    pub kind: ActivityType,
+    /// Stream URL, validated when type is [`ActivityType::Streaming`]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    /// Unix time (in milliseconds) of when the activity was added to the user's session
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<u64>,
+    /// Unix timestamps for start and/or end of the game
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timestamps: Option<ActivityTimestamps>,
+    /// Application ID for the game
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub application_id: Option<ApplicationId>,
+    /// [Field](StatusDisplayType) used to show the activity in the user's status text
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status_display_type: Option<StatusDisplayType>,
+    /// What the user is currently doing
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub details: Option<String>,
+    /// URL that is linked when clicking on the details text
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub details_url: Option<String>,
+    /// User's current party status, or text used for a custom status
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub state: Option<String>,
+    /// URL that is linked when clicking on the state text
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub state_url: Option<String>,
+    /// Emoji used for a custom status
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub emoji: Option<ActivityEmoji>,
+    /// Information for the current party of the user
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub party: Option<ActivityParty>,
+    /// Images for the presence and their hover texts
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub assets: Option<ActivityAssets>,
+    /// Secrets for Rich Presence joining and spectating
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub secrets: Option<ActivitySecrets>,
+    /// Whether or not the activity is an instanced game session
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub instance: Option<bool>,
+    /// [Activity flags](ActivityFlags) describing what the payload includes
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub flags: Option<ActivityFlags>,
+    /// Custom buttons shown in the Rich Presence (max 2)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub buttons: Vec<ActivityButton>,
+}
+
+impl Activity {
+    /// Creates an activity with only a `name` and a `kind`.
+    #[must_use]
+    pub const fn new(name: String, kind: ActivityType) -> Self {
+        Self {
+            name,
+            kind,
+            url: None,
+            created_at: None,
+            timestamps: None,
+            application_id: None,
+            status_display_type: None,
+            details: None,
+            details_url: None,
+            state: None,
+            state_url: None,
+            emoji: None,
+            party: None,
+            assets: None,
+            secrets: None,
+            instance: None,
+            flags: None,
+            buttons: Vec::new(),
+        }
+    }
+}
+
+/// Activity Timestamps
+///
+/// <https://discord.com/developers/docs/events/gateway-events#activity-object-activity-timestamps>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ActivityTimestamps {
+    /// Unix time (in milliseconds) of when the activity started
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub start: Option<u64>,
+    /// Unix time (in milliseconds) of when the activity ends
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub end: Option<u64>,
+}
+
+/// Activity Emoji
+///
+/// <https://discord.com/developers/docs/events/gateway-events#activity-object-activity-emoji>
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ActivityEmoji {
+    /// Name of the emoji
+    pub name: String,
+    /// ID of the emoji, if it is custom
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<EmojiId>,
+    /// Whether the emoji is animated
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub animated: Option<bool>,
+}
+
+/// Activity Party
+///
+/// <https://discord.com/developers/docs/events/gateway-events#activity-object-activity-party>
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ActivityParty {
+    /// ID of the party
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// Party's current and maximum size
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub size: Option<[u32; 2]>,
+}
+
+/// Activity Assets
+///
+/// <https://discord.com/developers/docs/events/gateway-events#activity-object-activity-assets>
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ActivityAssets {
+    /// Large image shown in the activity, as an asset ID or a media proxy image
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub large_image: Option<String>,
+    /// Text displayed when hovering over the large image
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub large_text: Option<String>,
+    /// URL that is opened when clicking on the large image
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub large_url: Option<String>,
+    /// Small image shown in the activity, as an asset ID or a media proxy image
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub small_image: Option<String>,
+    /// Text displayed when hovering over the small image
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub small_text: Option<String>,
+    /// URL that is opened when clicking on the small image
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub small_url: Option<String>,
+}
+
+/// Activity Secrets
+///
+/// <https://discord.com/developers/docs/events/gateway-events#activity-object-activity-secrets>
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ActivitySecrets {
+    /// Secret for joining a party
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub join: Option<String>,
+    /// Secret for spectating a game
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub spectate: Option<String>,
+    /// Secret for a specific instanced match
+    #[serde(rename = "match", default, skip_serializing_if = "Option::is_none")]
+    pub match_secret: Option<String>,
+}
+
+/// Activity Button
+///
+/// When received over the gateway, only the `label` of a button is sent.
+///
+/// <https://discord.com/developers/docs/events/gateway-events#activity-object-activity-buttons>
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ActivityButton {
+    /// Text shown on the button (1-32 characters)
+    pub label: String,
+    /// URL opened when clicking the button (1-512 characters)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+impl<'de> Deserialize<'de> for ActivityButton {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Button {
+            Label(String),
+            Full { label: String, url: Option<String> },
+        }
+
+        Ok(match Button::deserialize(deserializer)? {
+            Button::Label(label) => Self { label, url: None },
+            Button::Full { label, url } => Self { label, url },
+        })
+    }
+}
+
+int_enum! {
+    /// Activity Types
+    ///
+    /// <https://discord.com/developers/docs/events/gateway-events#activity-object-activity-types>
+    pub enum ActivityType: u8 {
+        /// Playing {name}
+        Playing = 0,
+        /// Streaming {details}
+        Streaming = 1,
+        /// Listening to {name}
+        Listening = 2,
+        /// Watching {name}
+        Watching = 3,
+        /// {emoji} {state}
+        Custom = 4,
+        /// Competing in {name}
+        Competing = 5,
+    }
+}
+
+int_enum! {
+    /// Status Display Types
+    ///
+    /// <https://discord.com/developers/docs/events/gateway-events#activity-object-status-display-types>
+    pub enum StatusDisplayType: u8 {
+        /// Playing {name}
+        Name = 0,
+        /// Playing {state}
+        State = 1,
+        /// Playing {details}
+        Details = 2,
+    }
+}
+
+bitflags! {
+    /// Activity Flags
+    ///
+    /// <https://discord.com/developers/docs/events/gateway-events#activity-object-activity-flags>
+    #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+    pub struct ActivityFlags: u32 {
+        /// Activity is an instanced game session
+        const INSTANCE = 1 << 0;
+        /// Activity can be joined
+        const JOIN = 1 << 1;
+        /// Activity can be spectated
+        const SPECTATE = 1 << 2;
+        /// Users can ask to join the activity
+        const JOIN_REQUEST = 1 << 3;
+        /// Activity can be synced, such as a song being listened to
+        const SYNC = 1 << 4;
+        /// Activity can be played
+        const PLAY = 1 << 5;
+        /// Party of the activity can only be joined by friends
+        const PARTY_PRIVACY_FRIENDS = 1 << 6;
+        /// Party of the activity can only be joined by users in the same voice channel
+        const PARTY_PRIVACY_VOICE_CHANNEL = 1 << 7;
+        /// Activity is an embedded app
+        const EMBEDDED = 1 << 8;
+    }
+}
+
+bitflags_serde!(ActivityFlags);
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::{ActivityType, Presence};
+
+    #[test]
+    fn received_activity() {
+        let presence: Presence = serde_json::from_value(json!({
+            "user": { "id": "80351110224678912" },
+            "status": "online",
+            "activities": [{
+                "name": "Rocket League",
+                "type": 6,
+                "created_at": 1_507_665_886_770_u64,
+                "details": "Ranked Duos: 2-1",
+                "state": "In a Group",
+                "emoji": { "name": "🚀" },
+                "party": { "id": "9dd6594e", "size": [2, 2] },
+                "assets": { "large_image": "351371005538729000", "large_text": "DFH Stadium" },
+                "timestamps": { "start": 1_507_665_886_770_u64 },
+            }],
+        }))
+        .expect("presence with an unknown activity type");
+
+        let activity = &presence.activities[0];
+        assert_eq!(activity.kind, ActivityType::Unknown(6));
+        assert_eq!(activity.details.as_deref(), Some("Ranked Duos: 2-1"));
+        assert_eq!(
+            activity.party.as_ref().and_then(|party| party.size),
+            Some([2, 2])
+        );
+        assert_eq!(
+            serde_json::to_value(activity).expect("serialize")["type"],
+            json!(6),
+        );
+    }
+}
@@ -0,0 +1,65 @@
+use serde::{Deserialize, Serialize};
+
+use crate::id::{GuildId, UserId};
+
+/// Request Guild Members Structure
+///
+/// Used to request all members for a guild or a list of guilds. When initially connecting,
+/// if you don't have the `GUILD_PRESENCES` [Gateway Intent], or if the guild is over 75k
+/// members, it will only send members who are in voice, plus the member for you (the
+/// connecting user). Otherwise, if a guild has over `large_threshold` members (value in
+/// the [Identify] payload), it will only send members who are online, have a role, have a
+/// nickname, or are in a voice channel, and if it has under `large_threshold` members, it
+/// will send all members.
+///
+/// If a client wishes to receive additional members, they need to explicitly request them
+/// via this operation. The server will send [Guild Members Chunk] events in response with
+/// up to 1000 members per chunk until all members that match the request have been sent.
+///
+/// <https://discord.com/developers/docs/events/gateway-events#request-guild-members>
+///
+/// [Gateway Intent]: crate::gateway::GatewayIntents
+/// [Identify]: crate::gateway::IdentifyPayload
+/// [Guild Members Chunk]: crate::gateway::events::GuildMembersChunk
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RequestGuildMembersPayload {
+    /// ID of the guild to get members for
+    pub guild_id: GuildId,
+    /// Which members to get
+    #[serde(flatten)]
+    pub filter: MemberFilter,
+    /// Used to specify if we want the presences of the matched members
+    #[serde(default)]
+    pub presences: bool,
+    /// Nonce to identify the [Guild Members Chunk] response (up to 32 bytes)
+    ///
+    /// [Guild Members Chunk]: crate::gateway::events::GuildMembersChunk
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nonce: Option<String>,
+}
+
+/// Members requested by a [`RequestGuildMembersPayload`], either by username or by ID.
+///
+/// <div class="warning">
+///
+/// Requesting members by username requires the `GUILD_MEMBERS` [Gateway Intent].
+/// </div>
+///
+/// [Gateway Intent]: crate::gateway::GatewayIntents
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum MemberFilter {
+    /// Members whose username starts with `query`.
+    Query {
+        /// String that username starts with, or an empty string to return all members
+        query: String,
+        /// Maximum number of members to send matching the `query`; a limit of `0` can be
+        /// used with an empty string `query` to return all members
+        limit: u32,
+    },
+    /// Members with the given IDs.
+    UserIds {
+        /// Used to specify which users you wish to fetch (up to 100)
+        user_ids: Vec<UserId>,
+    },
+}
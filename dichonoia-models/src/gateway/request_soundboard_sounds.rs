@@ -0,0 +1,17 @@
+use serde::{Deserialize, Serialize};
+
+use crate::id::GuildId;
+
+/// Request Soundboard Sounds Structure
+///
+/// Used to request soundboard sounds for a list of guilds. The gateway responds with
+/// [Soundboard Sounds] events for each guild.
+///
+/// <https://discord.com/developers/docs/events/gateway-events#request-soundboard-sounds>
+///
+/// [Soundboard Sounds]: crate::gateway::events::SoundboardSounds
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RequestSoundboardSoundsPayload {
+    /// IDs of the guilds to get soundboard sounds for
+    pub guild_ids: Vec<GuildId>,
+}
@@ -0,0 +1,97 @@
+//! Internally, Discord's payload format for the voice state update command is:
+//! ```json
+//! { "guild_id": <string>, "channel_id": <string | null>, "self_mute": <bool>, "self_deaf": <bool> }
+//! ```
+//! where a `null` channel ID means leaving the voice channel. Leaving is represented by
+//! its own variant instead, for which `self_mute` and `self_deaf` are meaningless.
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::id::{ChannelId, GuildId};
+
+/// Gateway Voice State Update Structure
+///
+/// Sent when a client wants to join, move, or disconnect from a voice channel. The
+/// gateway responds with a [Voice State Update] event, and with a [Voice Server Update]
+/// event when joining.
+///
+/// <https://discord.com/developers/docs/events/gateway-events#update-voice-state>
+///
+/// [Voice State Update]: crate::voice::VoiceState
+/// [Voice Server Update]: crate::gateway::events::VoiceServerUpdate
+#[derive(Debug, Clone, Copy)]
+pub enum VoiceStateUpdatePayload {
+    /// Joins a voice channel, or moves to it if already connected to another one of the
+    /// guild.
+    Join {
+        /// ID of the guild
+        guild_id: GuildId,
+        /// ID of the voice channel client wants to join
+        channel_id: ChannelId,
+        /// Whether the client is muted
+        self_mute: bool,
+        /// Whether the client deafened
+        self_deaf: bool,
+    },
+    /// Disconnects from the voice channel of the guild.
+    Leave {
+        /// ID of the guild
+        guild_id: GuildId,
+    },
+}
+
+#[derive(Serialize, Deserialize)]
+struct RawVoiceStateUpdate {
+    guild_id: GuildId,
+    channel_id: Option<ChannelId>,
+    self_mute: bool,
+    self_deaf: bool,
+}
+
+impl Serialize for VoiceStateUpdatePayload {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match *self {
+            Self::Join {
+                guild_id,
+                channel_id,
+                self_mute,
+                self_deaf,
+            } => RawVoiceStateUpdate {
+                guild_id,
+                channel_id: Some(channel_id),
+                self_mute,
+                self_deaf,
+            },
+            Self::Leave { guild_id } => RawVoiceStateUpdate {
+                guild_id,
+                channel_id: None,
+                self_mute: false,
+                self_deaf: false,
+            },
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for VoiceStateUpdatePayload {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let raw = RawVoiceStateUpdate::deserialize(deserializer)?;
+
+        let guild_id = raw.guild_id;
+
+        Ok(raw
+            .channel_id
+            .map_or(Self::Leave { guild_id }, |channel_id| Self::Join {
+                guild_id,
+                channel_id,
+                self_mute: raw.self_mute,
+                self_deaf: raw.self_deaf,
+            }))
+    }
+}
@@ -0,0 +1,167 @@
+use std::collections::HashSet;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Defines [`GuildFeature`] from the name of each feature, so that both always stay in
+/// sync.
+macro_rules! guild_features {
+    ( $( $(#[$meta:meta])* $variant:ident = $name:literal ),+ $(,)? ) => {
+        /// Guild Feature
+        ///
+        /// <https://discord.com/developers/docs/resources/guild#guild-object-guild-features>
+        #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+        pub enum GuildFeature {
+            $( $(#[$meta])* $variant, )+
+            /// Feature that is not modeled by this library, with its raw name.
+            Unknown(String),
+        }
+
+        impl GuildFeature {
+            /// Name of the feature, as sent by Discord.
+            #[must_use]
+            pub fn as_str(&self) -> &str {
+                match self {
+                    $( Self::$variant => $name, )+
+                    Self::Unknown(name) => name,
+                }
+            }
+        }
+
+        impl From<String> for GuildFeature {
+            fn from(value: String) -> Self {
+                match value.as_str() {
+                    $( $name => Self::$variant, )+
+                    _ => Self::Unknown(value),
+                }
+            }
+        }
+    };
+}
+
+guild_features! {
+    /// Guild has access to set an animated guild banner image
+    AnimatedBanner = "ANIMATED_BANNER",
+    /// Guild has access to set an animated guild icon
+    AnimatedIcon = "ANIMATED_ICON",
+    /// Guild is using the old permissions configuration behavior
+    ApplicationCommandPermissionsV2 = "APPLICATION_COMMAND_PERMISSIONS_V2",
+    /// Guild has set up auto moderation rules
+    AutoModeration = "AUTO_MODERATION",
+    /// Guild has access to set a guild banner image
+    Banner = "BANNER",
+    /// Guild can enable welcome screen, Membership Screening, stage channels and
+    /// discovery, and receives community updates
+    Community = "COMMUNITY",
+    /// Guild has enabled monetization
+    CreatorMonetizableProvisional = "CREATOR_MONETIZABLE_PROVISIONAL",
+    /// Guild has enabled the role subscription promo page
+    CreatorStorePage = "CREATOR_STORE_PAGE",
+    /// Guild has been set as a support server on the App Directory
+    DeveloperSupportServer = "DEVELOPER_SUPPORT_SERVER",
+    /// Guild is able to be discovered in the directory
+    Discoverable = "DISCOVERABLE",
+    /// Guild is able to be featured in the directory
+    Featurable = "FEATURABLE",
+    /// Guild has paused invites, preventing new users from joining
+    InvitesDisabled = "INVITES_DISABLED",
+    /// Guild has access to set an invite splash background
+    InviteSplash = "INVITE_SPLASH",
+    /// Guild has enabled Membership Screening
+    MemberVerificationGateEnabled = "MEMBER_VERIFICATION_GATE_ENABLED",
+    /// Guild has increased custom soundboard sound slots
+    MoreSoundboard = "MORE_SOUNDBOARD",
+    /// Guild has increased custom sticker slots
+    MoreStickers = "MORE_STICKERS",
+    /// Guild has access to create announcement channels
+    News = "NEWS",
+    /// Guild is partnered
+    Partnered = "PARTNERED",
+    /// Guild can be previewed before joining via Membership Screening or the directory
+    PreviewEnabled = "PREVIEW_ENABLED",
+    /// Guild has disabled alerts for join raids in the configured safety alerts channel
+    RaidAlertsDisabled = "RAID_ALERTS_DISABLED",
+    /// Guild is able to set role icons
+    RoleIcons = "ROLE_ICONS",
+    /// Guild has role subscriptions that can be purchased
+    RoleSubscriptionsAvailableForPurchase = "ROLE_SUBSCRIPTIONS_AVAILABLE_FOR_PURCHASE",
+    /// Guild has enabled role subscriptions
+    RoleSubscriptionsEnabled = "ROLE_SUBSCRIPTIONS_ENABLED",
+    /// Guild has created soundboard sounds
+    Soundboard = "SOUNDBOARD",
+    /// Guild has enabled ticketed events
+    TicketedEventsEnabled = "TICKETED_EVENTS_ENABLED",
+    /// Guild has access to set a vanity URL
+    VanityUrl = "VANITY_URL",
+    /// Guild is verified
+    Verified = "VERIFIED",
+    /// Guild has access to set 384kbps bitrate in voice (previously VIP voice servers)
+    VipRegions = "VIP_REGIONS",
+    /// Guild has enabled the welcome screen
+    WelcomeScreenEnabled = "WELCOME_SCREEN_ENABLED",
+    /// Guild has access to guest invites
+    GuestsEnabled = "GUESTS_ENABLED",
+    /// Guild has access to set guild tags
+    GuildTags = "GUILD_TAGS",
+    /// Guild is able to set gradient colors to roles
+    EnhancedRoleColors = "ENHANCED_ROLE_COLORS",
+}
+
+impl Serialize for GuildFeature {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for GuildFeature {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        String::deserialize(deserializer).map(Self::from)
+    }
+}
+
+/// Set of [`GuildFeature`]s enabled for a guild.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct GuildFeatures(HashSet<GuildFeature>);
+
+impl GuildFeatures {
+    /// Whether `feature` is enabled.
+    #[must_use]
+    pub fn contains(&self, feature: &GuildFeature) -> bool {
+        self.0.contains(feature)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &GuildFeature> {
+        self.0.iter()
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl<'a> IntoIterator for &'a GuildFeatures {
+    type Item = &'a GuildFeature;
+    type IntoIter = std::collections::hash_set::Iter<'a, GuildFeature>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+impl FromIterator<GuildFeature> for GuildFeatures {
+    fn from_iter<T: IntoIterator<Item = GuildFeature>>(iter: T) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
@@ -0,0 +1,108 @@
+use bitflags::bitflags;
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+
+use crate::{
+    id::RoleId,
+    image_hash::ImageHash,
+    user::{AvatarDecorationData, User},
+};
+
+/// Guild Member Object
+///
+/// <https://discord.com/developers/docs/resources/guild#guild-member-object>
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Member {
+    /// User this guild member represents
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user: Option<User>,
+    /// This user's guild nickname
+    #[serde(default)]
+    pub nick: Option<String>,
+    /// Member's guild avatar hash
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub avatar: Option<ImageHash>,
+    /// Member's guild banner hash
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub banner: Option<ImageHash>,
+    /// IDs of the roles of this member
+    #[serde(default)]
+    pub roles: Vec<RoleId>,
+    /// When the user joined the guild, or `None` for a guest
+    #[serde(with = "time::serde::rfc3339::option")]
+    pub joined_at: Option<OffsetDateTime>,
+    /// When the user started boosting the guild
+    #[serde(
+        default,
+        with = "time::serde::rfc3339::option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub premium_since: Option<OffsetDateTime>,
+    /// Whether the user is deafened in voice channels
+    #[serde(default)]
+    pub deaf: bool,
+    /// Whether the user is muted in voice channels
+    #[serde(default)]
+    pub mute: bool,
+    /// [Guild member flags](MemberFlags)
+    #[serde(default)]
+    pub flags: MemberFlags,
+    /// Whether the user has not yet passed the guild's Membership Screening requirements
+    #[serde(default)]
+    pub pending: bool,
+    /// Total permissions of the member in the channel, including overwrites, returned
+    /// when in the interaction object
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub permissions: Option<String>,
+    /// When the user's timeout will expire and the user will be able to communicate in the
+    /// guild again, or `None` if the user is not timed out
+    #[serde(
+        default,
+        with = "time::serde::rfc3339::option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub communication_disabled_until: Option<OffsetDateTime>,
+    /// Data for the member's guild avatar decoration
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub avatar_decoration_data: Option<AvatarDecorationData>,
+}
+
+impl Member {
+    /// Whether the member is timed out at `now`.
+    #[must_use]
+    pub fn is_timed_out(&self, now: OffsetDateTime) -> bool {
+        self.communication_disabled_until
+            .is_some_and(|until| until > now)
+    }
+}
+
+bitflags! {
+    /// Guild Member Flags
+    ///
+    /// <https://discord.com/developers/docs/resources/guild#guild-member-object-guild-member-flags>
+    #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+    pub struct MemberFlags: u32 {
+        /// Member has left and rejoined the guild
+        const DID_REJOIN = 1 << 0;
+        /// Member has completed onboarding
+        const COMPLETED_ONBOARDING = 1 << 1;
+        /// Member is exempt from guild verification requirements
+        const BYPASSES_VERIFICATION = 1 << 2;
+        /// Member has started onboarding
+        const STARTED_ONBOARDING = 1 << 3;
+        /// Member is a guest and can only access the voice channel they were invited to
+        const IS_GUEST = 1 << 4;
+        /// Member has started Server Guide new member actions
+        const STARTED_HOME_ACTIONS = 1 << 5;
+        /// Member has completed Server Guide new member actions
+        const COMPLETED_HOME_ACTIONS = 1 << 6;
+        /// Member's username, display name, or nickname is blocked by AutoMod
+        const AUTOMOD_QUARANTINED_USERNAME = 1 << 7;
+        /// Member has dismissed the DM settings upsell
+        const DM_SETTINGS_UPSELL_ACKNOWLEDGED = 1 << 9;
+        /// Member's guild tag is blocked by AutoMod
+        const AUTOMOD_QUARANTINED_GUILD_TAG = 1 << 10;
+    }
+}
+
+bitflags_serde!(MemberFlags);
@@ -1,12 +1,382 @@
+pub mod features;
+pub mod member;
+pub mod role;
+pub mod scheduled_event;
 pub(crate) mod unavailable;
 
-use serde::{Deserialize, Serialize};
+pub use features::{GuildFeature, GuildFeatures};
+pub use role::Role;
 
-use crate::id::GuildId;
+use bitflags::bitflags;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use time::OffsetDateTime;
 
+use crate::{
+    emoji::Emoji,
+    id::{ApplicationId, ChannelId, EmojiId, GuildId, UserId},
+    image_hash::ImageHash,
+    sticker::Sticker,
+};
+
+/// Guild Object
+///
+/// Guilds in Discord represent an isolated collection of users and channels, and are
+/// often referred to as "servers" in the UI.
+///
 /// <https://discord.com/developers/docs/resources/guild#guild-object>
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Guild {
+    /// Guild ID
     pub id: GuildId,
-    // TODO: add other fields
+    /// Guild name (2-100 characters, excluding trailing and leading whitespace)
+    pub name: String,
+    /// Icon hash
+    pub icon: Option<ImageHash>,
+    /// Splash hash
+    pub splash: Option<ImageHash>,
+    /// Discovery splash hash; only present for guilds with the
+    /// [`GuildFeature::Discoverable`] feature
+    pub discovery_splash: Option<ImageHash>,
+    /// ID of owner
+    pub owner_id: UserId,
+    /// ID of afk channel
+    pub afk_channel_id: Option<ChannelId>,
+    /// [AFK timeout](AfkTimeout)
+    pub afk_timeout: AfkTimeout,
+    /// [Widget settings](GuildWidget), if they were sent
+    #[serde(flatten)]
+    pub widget: Option<GuildWidget>,
+    /// [Verification level](VerificationLevel) required for the guild
+    pub verification_level: VerificationLevel,
+    /// Default [message notifications level](DefaultMessageNotificationLevel)
+    pub default_message_notifications: DefaultMessageNotificationLevel,
+    /// [Explicit content filter level](ExplicitContentFilterLevel)
+    pub explicit_content_filter: ExplicitContentFilterLevel,
+    /// Roles in the guild
+    pub roles: Vec<Role>,
+    /// Custom guild emojis
+    pub emojis: Vec<Emoji>,
+    /// Enabled [guild features](GuildFeatures)
+    pub features: GuildFeatures,
+    /// Required [MFA level](MfaLevel) for the guild
+    pub mfa_level: MfaLevel,
+    /// Application ID of the guild creator if it is bot-created
+    pub application_id: Option<ApplicationId>,
+    /// ID of the channel where guild notices such as welcome messages and boost events
+    /// are posted
+    pub system_channel_id: Option<ChannelId>,
+    /// [System channel flags](SystemChannelFlags)
+    pub system_channel_flags: SystemChannelFlags,
+    /// ID of the channel where Community guilds can display rules and/or guidelines
+    pub rules_channel_id: Option<ChannelId>,
+    /// Maximum number of presences for the guild (`None` is always returned, apart from
+    /// the largest of guilds)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_presences: Option<u32>,
+    /// Maximum number of members for the guild
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_members: Option<u32>,
+    /// Vanity URL code for the guild
+    pub vanity_url_code: Option<String>,
+    /// Description of a guild
+    pub description: Option<String>,
+    /// Banner hash
+    pub banner: Option<ImageHash>,
+    /// [Premium tier](PremiumTier) (Server Boost level)
+    pub premium_tier: PremiumTier,
+    /// Number of boosts this guild currently has
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub premium_subscription_count: Option<u32>,
+    /// Preferred locale of a Community guild; used in server discovery and notices from
+    /// Discord, and sent in interactions; defaults to `"en-US"`
+    pub preferred_locale: String,
+    /// ID of the channel where admins and moderators of Community guilds receive notices
+    /// from Discord
+    pub public_updates_channel_id: Option<ChannelId>,
+    /// Maximum amount of users in a video channel
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_video_channel_users: Option<u32>,
+    /// Maximum amount of users in a stage video channel
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_stage_video_channel_users: Option<u32>,
+    /// Approximate number of members in this guild, returned from some REST endpoints
+    /// when `with_counts` is `true`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub approximate_member_count: Option<u32>,
+    /// Approximate number of non-offline members in this guild, returned from some REST
+    /// endpoints when `with_counts` is `true`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub approximate_presence_count: Option<u32>,
+    /// Guild [NSFW level](NsfwLevel)
+    pub nsfw_level: NsfwLevel,
+    /// Custom guild stickers
+    #[serde(default)]
+    pub stickers: Vec<Sticker>,
+    /// Whether the guild has the boost progress bar enabled
+    pub premium_progress_bar_enabled: bool,
+    /// ID of the channel where admins and moderators of Community guilds receive safety
+    /// alerts from Discord
+    pub safety_alerts_channel_id: Option<ChannelId>,
+    /// Welcome screen of a Community guild, shown to new members
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub welcome_screen: Option<WelcomeScreen>,
+    /// Incident actions and detected incidents of the guild
+    #[serde(default)]
+    pub incidents_data: Option<IncidentsData>,
+}
+
+/// Welcome Screen Object
+///
+/// <https://discord.com/developers/docs/resources/guild#welcome-screen-object>
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WelcomeScreen {
+    /// Server description shown in the welcome screen
+    pub description: Option<String>,
+    /// Channels shown in the welcome screen, up to 5
+    pub welcome_channels: Vec<WelcomeScreenChannel>,
+}
+
+/// Welcome Screen Channel Structure
+///
+/// <https://discord.com/developers/docs/resources/guild#welcome-screen-object-welcome-screen-channel-structure>
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WelcomeScreenChannel {
+    /// Channel's ID
+    pub channel_id: ChannelId,
+    /// Description shown for the channel
+    pub description: String,
+    /// Emoji ID, if the emoji is custom
+    pub emoji_id: Option<EmojiId>,
+    /// Emoji name if custom, the unicode character if standard, or `None` if no emoji
+    /// is set
+    pub emoji_name: Option<String>,
+}
+
+/// Incidents Data Object
+///
+/// <https://discord.com/developers/docs/resources/guild#incidents-data-object>
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IncidentsData {
+    /// When invites get enabled again
+    #[serde(with = "time::serde::rfc3339::option")]
+    pub invites_disabled_until: Option<OffsetDateTime>,
+    /// When direct messages get enabled again
+    #[serde(with = "time::serde::rfc3339::option")]
+    pub dms_disabled_until: Option<OffsetDateTime>,
+    /// When DM spam was detected
+    #[serde(default, with = "time::serde::rfc3339::option")]
+    pub dm_spam_detected_at: Option<OffsetDateTime>,
+    /// When raid was detected
+    #[serde(default, with = "time::serde::rfc3339::option")]
+    pub raid_detected_at: Option<OffsetDateTime>,
+}
+
+/// Widget settings of a guild.
+///
+/// Discord sends these as a `widget_enabled` and a `widget_channel_id` field, but the
+/// channel is only relevant while the widget is enabled.
+///
+/// <https://discord.com/developers/docs/resources/guild#guild-widget-settings-object>
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GuildWidget {
+    /// The server widget is disabled
+    Disabled,
+    /// The server widget is enabled
+    Enabled {
+        /// Channel that the widget will generate an invite to, or `None` if set to no
+        /// invite
+        channel_id: Option<ChannelId>,
+    },
+}
+
+#[derive(Serialize, Deserialize)]
+struct RawGuildWidget {
+    widget_enabled: bool,
+    #[serde(default)]
+    widget_channel_id: Option<ChannelId>,
+}
+
+impl Serialize for GuildWidget {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match *self {
+            Self::Disabled => RawGuildWidget {
+                widget_enabled: false,
+                widget_channel_id: None,
+            },
+            Self::Enabled { channel_id } => RawGuildWidget {
+                widget_enabled: true,
+                widget_channel_id: channel_id,
+            },
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for GuildWidget {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let raw = RawGuildWidget::deserialize(deserializer)?;
+        Ok(if raw.widget_enabled {
+            Self::Enabled {
+                channel_id: raw.widget_channel_id,
+            }
+        } else {
+            Self::Disabled
+        })
+    }
+}
+
+int_enum! {
+    /// AFK timeout of a guild, in seconds
+    ///
+    /// <https://discord.com/developers/docs/resources/guild#modify-guild-json-params>
+    pub enum AfkTimeout: u16 {
+        /// 1 minute
+        OneMinute = 60,
+        /// 5 minutes
+        FiveMinutes = 300,
+        /// 15 minutes
+        FifteenMinutes = 900,
+        /// 30 minutes
+        ThirtyMinutes = 1800,
+        /// 1 hour
+        OneHour = 3600,
+    }
+}
+
+impl AfkTimeout {
+    /// Duration of the timeout.
+    #[must_use]
+    pub fn duration(self) -> std::time::Duration {
+        std::time::Duration::from_secs(u64::from(u16::from(self)))
+    }
+}
+
+int_enum! {
+    /// Verification Level
+    ///
+    /// <https://discord.com/developers/docs/resources/guild#guild-object-verification-level>
+    pub enum VerificationLevel: u8 {
+        /// Unrestricted
+        None = 0,
+        /// Must have verified email on account
+        Low = 1,
+        /// Must be registered on Discord for longer than 5 minutes
+        Medium = 2,
+        /// Must be a member of the server for longer than 10 minutes
+        High = 3,
+        /// Must have a verified phone number
+        VeryHigh = 4,
+    }
+}
+
+int_enum! {
+    /// Default Message Notification Level
+    ///
+    /// <https://discord.com/developers/docs/resources/guild#guild-object-default-message-notification-level>
+    pub enum DefaultMessageNotificationLevel: u8 {
+        /// Members will receive notifications for all messages by default
+        AllMessages = 0,
+        /// Members will receive notifications only for messages that @mention them by
+        /// default
+        OnlyMentions = 1,
+    }
+}
+
+int_enum! {
+    /// Explicit Content Filter Level
+    ///
+    /// <https://discord.com/developers/docs/resources/guild#guild-object-explicit-content-filter-level>
+    pub enum ExplicitContentFilterLevel: u8 {
+        /// Media content will not be scanned
+        Disabled = 0,
+        /// Media content sent by members without roles will be scanned
+        MembersWithoutRoles = 1,
+        /// Media content sent by all members will be scanned
+        AllMembers = 2,
+    }
+}
+
+int_enum! {
+    /// MFA Level
+    ///
+    /// <https://discord.com/developers/docs/resources/guild#guild-object-mfa-level>
+    pub enum MfaLevel: u8 {
+        /// Guild has no MFA/2FA requirement for moderation actions
+        None = 0,
+        /// Guild has a 2FA requirement for moderation actions
+        Elevated = 1,
+    }
+}
+
+int_enum! {
+    /// Guild NSFW Level
+    ///
+    /// <https://discord.com/developers/docs/resources/guild#guild-object-guild-nsfw-level>
+    pub enum NsfwLevel: u8 {
+        Default = 0,
+        Explicit = 1,
+        Safe = 2,
+        AgeRestricted = 3,
+    }
+}
+
+int_enum! {
+    /// Premium Tier
+    ///
+    /// <https://discord.com/developers/docs/resources/guild#guild-object-premium-tier>
+    pub enum PremiumTier: u8 {
+        /// Guild has not unlocked any Server Boost perks
+        None = 0,
+        /// Guild has unlocked Server Boost level 1 perks
+        Tier1 = 1,
+        /// Guild has unlocked Server Boost level 2 perks
+        Tier2 = 2,
+        /// Guild has unlocked Server Boost level 3 perks
+        Tier3 = 3,
+    }
+}
+
+bitflags! {
+    /// System Channel Flags
+    ///
+    /// <https://discord.com/developers/docs/resources/guild#guild-object-system-channel-flags>
+    #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+    pub struct SystemChannelFlags: u32 {
+        /// Suppress member join notifications
+        const SUPPRESS_JOIN_NOTIFICATIONS = 1 << 0;
+        /// Suppress server boost notifications
+        const SUPPRESS_PREMIUM_SUBSCRIPTIONS = 1 << 1;
+        /// Suppress server setup tips
+        const SUPPRESS_GUILD_REMINDER_NOTIFICATIONS = 1 << 2;
+        /// Hide member join sticker reply buttons
+        const SUPPRESS_JOIN_NOTIFICATION_REPLIES = 1 << 3;
+        /// Suppress role subscription purchase and renewal notifications
+        const SUPPRESS_ROLE_SUBSCRIPTION_PURCHASE_NOTIFICATIONS = 1 << 4;
+        /// Hide role subscription sticker reply buttons
+        const SUPPRESS_ROLE_SUBSCRIPTION_PURCHASE_NOTIFICATION_REPLIES = 1 << 5;
+    }
+}
+
+bitflags_serde!(SystemChannelFlags);
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::AfkTimeout;
+
+    #[test]
+    fn unlisted_afk_timeout() {
+        let timeout: AfkTimeout = serde_json::from_str("120").expect("unlisted timeout");
+
+        assert_eq!(timeout, AfkTimeout::Unknown(120));
+        assert_eq!(timeout.duration(), Duration::from_mins(2));
+        assert_eq!(serde_json::to_string(&timeout).expect("serialize"), "120");
+    }
 }
@@ -0,0 +1,126 @@
+use bitflags::bitflags;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    id::{IntegrationId, RoleId, SkuId, UserId},
+    image_hash::ImageHash,
+};
+
+/// Role Object
+///
+/// Roles represent a set of permissions attached to a group of users.
+///
+/// <https://discord.com/developers/docs/topics/permissions#role-object>
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Role {
+    /// Role ID
+    pub id: RoleId,
+    /// Role name
+    pub name: String,
+    /// Integer representation of hexadecimal color code (deprecated, use `colors`)
+    pub color: u32,
+    /// [Colors](RoleColors) of the role
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub colors: Option<RoleColors>,
+    /// If this role is pinned in the user listing
+    pub hoist: bool,
+    /// Role icon hash
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub icon: Option<ImageHash>,
+    /// Role unicode emoji
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub unicode_emoji: Option<String>,
+    /// Position of this role (roles with the same position are sorted by ID)
+    pub position: i32,
+    /// Permission bit set
+    pub permissions: String,
+    /// Whether this role is managed by an integration
+    pub managed: bool,
+    /// Whether this role is mentionable
+    pub mentionable: bool,
+    /// [Tags](RoleTags) this role has
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tags: Option<RoleTags>,
+    /// [Role flags](RoleFlags)
+    #[serde(default)]
+    pub flags: RoleFlags,
+}
+
+/// Role Colors Object
+///
+/// <https://discord.com/developers/docs/topics/permissions#role-object-role-colors-object>
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct RoleColors {
+    /// Primary color for the role
+    pub primary_color: u32,
+    /// Secondary color for the role, this will make the role a gradient between the other
+    /// provided colors
+    pub secondary_color: Option<u32>,
+    /// Tertiary color for the role, this will turn the gradient into a holographic style
+    pub tertiary_color: Option<u32>,
+}
+
+/// Role Tags Object
+///
+/// Discord sends the boolean tags as `null` when they are set, and omits them otherwise.
+///
+/// <https://discord.com/developers/docs/topics/permissions#role-object-role-tags-structure>
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RoleTags {
+    /// ID of the bot this role belongs to
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bot_id: Option<UserId>,
+    /// ID of the integration this role belongs to
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub integration_id: Option<IntegrationId>,
+    /// Whether this is the guild's Booster role
+    #[serde(default, with = "present", skip_serializing_if = "present::is_absent")]
+    pub premium_subscriber: bool,
+    /// ID of this role's subscription SKU and listing
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subscription_listing_id: Option<SkuId>,
+    /// Whether this role is available for purchase
+    #[serde(default, with = "present", skip_serializing_if = "present::is_absent")]
+    pub available_for_purchase: bool,
+    /// Whether this role is a guild's linked role
+    #[serde(default, with = "present", skip_serializing_if = "present::is_absent")]
+    pub guild_connections: bool,
+}
+
+/// (De)serializes a tag that is `true` when present as `null`, and `false` when absent.
+mod present {
+    use serde::{Deserialize, Deserializer, Serializer, de::IgnoredAny};
+
+    #[expect(clippy::trivially_copy_pass_by_ref, reason = "required by serde")]
+    pub const fn is_absent(value: &bool) -> bool {
+        !*value
+    }
+
+    #[expect(clippy::trivially_copy_pass_by_ref, reason = "required by serde")]
+    pub fn serialize<S>(_: &bool, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_none()
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<bool, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        IgnoredAny::deserialize(deserializer).map(|_| true)
+    }
+}
+
+bitflags! {
+    /// Role Flags
+    ///
+    /// <https://discord.com/developers/docs/topics/permissions#role-object-role-flags>
+    #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+    pub struct RoleFlags: u32 {
+        /// Role can be selected by members in an onboarding prompt
+        const IN_PROMPT = 1 << 0;
+    }
+}
+
+bitflags_serde!(RoleFlags);
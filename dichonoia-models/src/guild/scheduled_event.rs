@@ -0,0 +1,196 @@
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+
+use crate::{
+    id::{ChannelId, GuildId, ScheduledEventId, StageInstanceId, UserId},
+    image_hash::ImageHash,
+    user::User,
+};
+
+/// Guild Scheduled Event Object
+///
+/// A representation of a scheduled event in a guild.
+///
+/// <https://discord.com/developers/docs/resources/guild-scheduled-event#guild-scheduled-event-object>
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GuildScheduledEvent {
+    /// ID of the scheduled event
+    pub id: ScheduledEventId,
+    /// Guild ID which the scheduled event belongs to
+    pub guild_id: GuildId,
+    /// Channel ID in which the scheduled event will be hosted, or `None` if the
+    /// [entity type](ScheduledEventEntityType) is `External`
+    pub channel_id: Option<ChannelId>,
+    /// ID of the user that created the scheduled event
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub creator_id: Option<UserId>,
+    /// Name of the scheduled event (1-100 characters)
+    pub name: String,
+    /// Description of the scheduled event (1-1000 characters)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Time the scheduled event will start
+    #[serde(with = "time::serde::rfc3339")]
+    pub scheduled_start_time: OffsetDateTime,
+    /// Time the scheduled event will end, required if the
+    /// [entity type](ScheduledEventEntityType) is `External`
+    #[serde(default, with = "time::serde::rfc3339::option")]
+    pub scheduled_end_time: Option<OffsetDateTime>,
+    /// [Privacy level](ScheduledEventPrivacyLevel) of the scheduled event
+    pub privacy_level: ScheduledEventPrivacyLevel,
+    /// [Status](ScheduledEventStatus) of the scheduled event
+    pub status: ScheduledEventStatus,
+    /// [Type](ScheduledEventEntityType) of the scheduled event
+    pub entity_type: ScheduledEventEntityType,
+    /// ID of the Stage instance hosting the event, if any
+    pub entity_id: Option<StageInstanceId>,
+    /// Additional metadata for the scheduled event
+    pub entity_metadata: Option<ScheduledEventEntityMetadata>,
+    /// User that created the scheduled event
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub creator: Option<User>,
+    /// Number of users subscribed to the scheduled event
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_count: Option<u32>,
+    /// Cover image hash of the scheduled event
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub image: Option<ImageHash>,
+    /// Definition for how often the scheduled event should recur
+    #[serde(default)]
+    pub recurrence_rule: Option<RecurrenceRule>,
+}
+
+/// Guild Scheduled Event Recurrence Rule
+///
+/// Discord only supports a subset of the iCalendar RFC 5545 recurrence rules.
+///
+/// <https://discord.com/developers/docs/resources/guild-scheduled-event#guild-scheduled-event-recurrence-rule-object>
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecurrenceRule {
+    /// Starting time of the recurrence interval
+    #[serde(with = "time::serde::rfc3339")]
+    pub start: OffsetDateTime,
+    /// Ending time of the recurrence interval
+    #[serde(default, with = "time::serde::rfc3339::option")]
+    pub end: Option<OffsetDateTime>,
+    /// How often the event occurs
+    pub frequency: RecurrenceFrequency,
+    /// Spacing between the events, defined by `frequency`
+    pub interval: u32,
+    /// Specific days within a week for the event to recur on
+    pub by_weekday: Option<Vec<RecurrenceWeekday>>,
+    /// Specific days within a specific week (1-5) to recur on
+    pub by_n_weekday: Option<Vec<RecurrenceNWeekday>>,
+    /// Specific months to recur on
+    pub by_month: Option<Vec<RecurrenceMonth>>,
+    /// Specific dates within a month to recur on
+    pub by_month_day: Option<Vec<u8>>,
+    /// Specific days within a year to recur on (1-364)
+    pub by_year_day: Option<Vec<u16>>,
+    /// Total amount of times that the event is allowed to recur before stopping
+    pub count: Option<u32>,
+}
+
+/// Guild Scheduled Event Recurrence Rule N Weekday Structure
+///
+/// <https://discord.com/developers/docs/resources/guild-scheduled-event#guild-scheduled-event-recurrence-rule-object-guild-scheduled-event-recurrence-rule-nweekday-structure>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RecurrenceNWeekday {
+    /// Week to recur on (1-5)
+    pub n: u8,
+    /// Day within the week to recur on
+    pub day: RecurrenceWeekday,
+}
+
+int_enum! {
+    /// Guild Scheduled Event Recurrence Rule Frequency
+    ///
+    /// <https://discord.com/developers/docs/resources/guild-scheduled-event#guild-scheduled-event-recurrence-rule-object-guild-scheduled-event-recurrence-rule-frequency>
+    pub enum RecurrenceFrequency: u8 {
+        Yearly = 0,
+        Monthly = 1,
+        Weekly = 2,
+        Daily = 3,
+    }
+}
+
+int_enum! {
+    /// Guild Scheduled Event Recurrence Rule Weekday
+    ///
+    /// <https://discord.com/developers/docs/resources/guild-scheduled-event#guild-scheduled-event-recurrence-rule-object-guild-scheduled-event-recurrence-rule-weekday>
+    pub enum RecurrenceWeekday: u8 {
+        Monday = 0,
+        Tuesday = 1,
+        Wednesday = 2,
+        Thursday = 3,
+        Friday = 4,
+        Saturday = 5,
+        Sunday = 6,
+    }
+}
+
+int_enum! {
+    /// Guild Scheduled Event Recurrence Rule Month
+    ///
+    /// <https://discord.com/developers/docs/resources/guild-scheduled-event#guild-scheduled-event-recurrence-rule-object-guild-scheduled-event-recurrence-rule-month>
+    pub enum RecurrenceMonth: u8 {
+        January = 1,
+        February = 2,
+        March = 3,
+        April = 4,
+        May = 5,
+        June = 6,
+        July = 7,
+        August = 8,
+        September = 9,
+        October = 10,
+        November = 11,
+        December = 12,
+    }
+}
+
+/// Guild Scheduled Event Entity Metadata
+///
+/// <https://discord.com/developers/docs/resources/guild-scheduled-event#guild-scheduled-event-object-guild-scheduled-event-entity-metadata>
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScheduledEventEntityMetadata {
+    /// Location of the event (1-100 characters), required for `External` events
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub location: Option<String>,
+}
+
+int_enum! {
+    /// Guild Scheduled Event Privacy Level
+    ///
+    /// <https://discord.com/developers/docs/resources/guild-scheduled-event#guild-scheduled-event-object-guild-scheduled-event-privacy-level>
+    pub enum ScheduledEventPrivacyLevel: u8 {
+        /// The scheduled event is only accessible to guild members
+        GuildOnly = 2,
+    }
+}
+
+int_enum! {
+    /// Guild Scheduled Event Entity Types
+    ///
+    /// <https://discord.com/developers/docs/resources/guild-scheduled-event#guild-scheduled-event-object-guild-scheduled-event-entity-types>
+    pub enum ScheduledEventEntityType: u8 {
+        StageInstance = 1,
+        Voice = 2,
+        External = 3,
+    }
+}
+
+int_enum! {
+    /// Guild Scheduled Event Status
+    ///
+    /// Once status is set to `Completed` or `Canceled`, the status can no longer be
+    /// updated.
+    ///
+    /// <https://discord.com/developers/docs/resources/guild-scheduled-event#guild-scheduled-event-object-guild-scheduled-event-status>
+    pub enum ScheduledEventStatus: u8 {
+        Scheduled = 1,
+        Active = 2,
+        Completed = 3,
+        Canceled = 4,
+    }
+}
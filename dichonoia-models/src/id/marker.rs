@@ -17,4 +17,24 @@ macro_rules! define_entities {
     };
 }
 
-define_entities![Guild, User, Application];
+define_entities![
+    Guild,
+    User,
+    Application,
+    Channel,
+    Emoji,
+    ForumTag,
+    Sound,
+    Role,
+    RoleSubscriptionListing,
+    ScheduledEvent,
+    StageInstance,
+    Sticker,
+    StickerPack,
+    Message,
+    Attachment,
+    Interaction,
+    Webhook,
+    Integration,
+    Sku,
+];
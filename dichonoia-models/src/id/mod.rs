@@ -1,15 +1,59 @@
 pub mod marker;
 
+use serde::de::{self, Unexpected, Visitor};
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
-use std::{marker::PhantomData, num::NonZeroU64};
+use std::{
+    cmp::Ordering,
+    fmt,
+    hash::{Hash, Hasher},
+    marker::PhantomData,
+    num::{NonZeroU64, ParseIntError},
+    str::FromStr,
+};
+use time::{Duration, OffsetDateTime};
 
-use crate::id::marker::{Application, Entity, Guild, User};
+use crate::id::marker::{
+    Application, Attachment, Channel, Emoji, Entity, ForumTag, Guild, Integration, Interaction,
+    Message, Role, RoleSubscriptionListing, ScheduledEvent, Sku, Sound, StageInstance, Sticker,
+    StickerPack, User, Webhook,
+};
 
 pub type ApplicationId = Snowflake<Application>;
+pub type AttachmentId = Snowflake<Attachment>;
+pub type ChannelId = Snowflake<Channel>;
+pub type EmojiId = Snowflake<Emoji>;
+pub type ForumTagId = Snowflake<ForumTag>;
 pub type GuildId = Snowflake<Guild>;
+pub type IntegrationId = Snowflake<Integration>;
+pub type InteractionId = Snowflake<Interaction>;
+pub type MessageId = Snowflake<Message>;
+pub type RoleId = Snowflake<Role>;
+pub type RoleSubscriptionListingId = Snowflake<RoleSubscriptionListing>;
+pub type ScheduledEventId = Snowflake<ScheduledEvent>;
+pub type SkuId = Snowflake<Sku>;
+pub type SoundId = Snowflake<Sound>;
+pub type StageInstanceId = Snowflake<StageInstance>;
+pub type StickerId = Snowflake<Sticker>;
+pub type StickerPackId = Snowflake<StickerPack>;
 pub type UserId = Snowflake<User>;
+pub type WebhookId = Snowflake<Webhook>;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// Discord epoch, the first second of 2015, in milliseconds since the Unix epoch.
+///
+/// <https://discord.com/developers/docs/reference#snowflakes>
+pub const DISCORD_EPOCH: i64 = 1_420_070_400_000;
+
+/// Largest timestamp, in milliseconds since the [Discord epoch](DISCORD_EPOCH), that fits
+/// in the 42 bits of a snowflake.
+const MAX_TIMESTAMP: u64 = (1 << 42) - 1;
+
+/// Unique ID of an entity of type `T`.
+///
+/// Snowflakes are ordered by the time at which they were created, so comparing two IDs
+/// compares their ages.
+///
+/// <https://discord.com/developers/docs/reference#snowflakes>
+#[derive(Debug, Clone, Copy)]
 pub struct Snowflake<T: Entity> {
     inner: NonZeroU64,
     entity: PhantomData<fn(T) -> T>,
@@ -46,6 +90,89 @@ impl<T: Entity> Snowflake<T> {
     pub const fn cast_from<U: Entity>(value: Snowflake<U>) -> Self {
         Self::new_nonzero(value.inner)
     }
+
+    /// Creates the smallest snowflake that could have been generated at `timestamp`.
+    ///
+    /// The result is not the ID of any entity, but can be used as a `before` or `after`
+    /// bound when paginating by time. Returns `None` if `timestamp` is not strictly after
+    /// the [Discord epoch](DISCORD_EPOCH), or too far in the future to be represented.
+    #[must_use]
+    pub fn from_timestamp(timestamp: OffsetDateTime) -> Option<Self> {
+        let millis = (timestamp - OffsetDateTime::UNIX_EPOCH).whole_milliseconds()
+            - i128::from(DISCORD_EPOCH);
+        let millis = u64::try_from(millis)
+            .ok()
+            .filter(|millis| *millis <= MAX_TIMESTAMP)?;
+        NonZeroU64::new(millis << 22).map(Self::new_nonzero)
+    }
+
+    /// Time at which the snowflake was generated, with millisecond precision.
+    #[must_use]
+    pub fn timestamp(self) -> OffsetDateTime {
+        // Fits in 42 bits, so it can never wrap.
+        let millis = (self.get() >> 22).cast_signed();
+        OffsetDateTime::UNIX_EPOCH + Duration::milliseconds(DISCORD_EPOCH + millis)
+    }
+
+    /// Internal ID of the worker that generated the snowflake.
+    #[must_use]
+    pub const fn worker_id(self) -> u8 {
+        ((self.get() >> 17) & 0x1F) as u8
+    }
+
+    /// Internal ID of the process that generated the snowflake.
+    #[must_use]
+    pub const fn process_id(self) -> u8 {
+        ((self.get() >> 12) & 0x1F) as u8
+    }
+
+    /// Incremented for every snowflake generated on the process.
+    #[must_use]
+    pub const fn increment(self) -> u16 {
+        (self.get() & 0xFFF) as u16
+    }
+}
+
+// Implemented by hand, as derives would require the marker types to implement them too.
+
+impl<T: Entity> PartialEq for Snowflake<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.inner == other.inner
+    }
+}
+
+impl<T: Entity> Eq for Snowflake<T> {}
+
+impl<T: Entity> PartialOrd for Snowflake<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: Entity> Ord for Snowflake<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.inner.cmp(&other.inner)
+    }
+}
+
+impl<T: Entity> Hash for Snowflake<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.inner.hash(state);
+    }
+}
+
+impl<T: Entity> fmt::Display for Snowflake<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.inner.fmt(f)
+    }
+}
+
+impl<T: Entity> FromStr for Snowflake<T> {
+    type Err = ParseIntError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse().map(Self::new_nonzero)
+    }
 }
 
 impl<T: Entity> Serialize for Snowflake<T> {
@@ -53,7 +180,7 @@ impl<T: Entity> Serialize for Snowflake<T> {
     where
         S: Serializer,
     {
-        serializer.serialize_str(&self.inner.get().to_string())
+        serializer.collect_str(self)
     }
 }
 
@@ -62,8 +189,315 @@ impl<'de, T: Entity> Deserialize<'de> for Snowflake<T> {
     where
         D: Deserializer<'de>,
     {
-        let value = String::deserialize(deserializer)?;
-        let n = value.parse().map_err(serde::de::Error::custom)?;
-        Ok(Self::new_nonzero(n))
+        deserializer.deserialize_any(SnowflakeVisitor(PhantomData))
+    }
+}
+
+/// Snowflakes are strings over JSON, but integers over ETF and in some payloads.
+struct SnowflakeVisitor<T: Entity>(PhantomData<fn(T) -> T>);
+
+impl<T: Entity> Visitor<'_> for SnowflakeVisitor<T> {
+    type Value = Snowflake<T>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a non-zero snowflake as a string or an unsigned integer")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        let n = v
+            .parse()
+            .map_err(|_| E::invalid_value(Unexpected::Str(v), &self))?;
+        NonZeroU64::new(n)
+            .map(Snowflake::new_nonzero)
+            .ok_or_else(|| E::invalid_value(Unexpected::Str(v), &self))
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<Self::Value, E> {
+        NonZeroU64::new(v)
+            .map(Snowflake::new_nonzero)
+            .ok_or_else(|| E::invalid_value(Unexpected::Unsigned(v), &self))
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<Self::Value, E> {
+        let n = u64::try_from(v).map_err(|_| E::invalid_value(Unexpected::Signed(v), &self))?;
+        self.visit_u64(n)
+    }
+}
+
+/// Serializes snowflakes as integers rather than strings, for use with
+/// `#[serde(with = "dichonoia_models::id::integer")]` in storage formats.
+///
+/// Optional snowflakes and lists of snowflakes use the [`option`](integer::option) and
+/// [`vec`](integer::vec) submodules instead. Snowflakes are deserialized as integers too,
+/// so that formats that are not self-describing, such as bincode, can read them back.
+pub mod integer {
+    use std::marker::PhantomData;
+
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use super::{Entity, Snowflake, SnowflakeVisitor};
+
+    /// Snowflake that is (de)serialized as an unsigned integer.
+    struct Integer<T: Entity>(Snowflake<T>);
+
+    impl<T: Entity> Serialize for Integer<T> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.serialize_u64(self.0.get())
+        }
+    }
+
+    impl<'de, T: Entity> Deserialize<'de> for Integer<T> {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            deserializer
+                .deserialize_u64(SnowflakeVisitor(PhantomData))
+                .map(Self)
+        }
+    }
+
+    /// Serializes a snowflake as an unsigned integer.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the serializer fails.
+    pub fn serialize<T: Entity, S: Serializer>(
+        id: &Snowflake<T>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        Integer(*id).serialize(serializer)
+    }
+
+    /// Deserializes a snowflake from an unsigned integer.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the value is not a non-zero integer.
+    pub fn deserialize<'de, T: Entity, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Snowflake<T>, D::Error> {
+        Integer::deserialize(deserializer).map(|Integer(id)| id)
+    }
+
+    /// Same as [`integer`](super::integer), for `Option<Snowflake<T>>`.
+    pub mod option {
+        use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+        use super::{Entity, Integer, Snowflake};
+
+        /// Serializes an optional snowflake as an optional unsigned integer.
+        ///
+        /// # Errors
+        ///
+        /// Returns an error if the serializer fails.
+        pub fn serialize<T: Entity, S: Serializer>(
+            id: &Option<Snowflake<T>>,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            id.map(Integer).serialize(serializer)
+        }
+
+        /// Deserializes an optional snowflake from an optional unsigned integer.
+        ///
+        /// # Errors
+        ///
+        /// Returns an error if the value is neither none nor a non-zero integer.
+        pub fn deserialize<'de, T: Entity, D: Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Option<Snowflake<T>>, D::Error> {
+            Option::deserialize(deserializer).map(|id| id.map(|Integer(id)| id))
+        }
+    }
+
+    /// Same as [`integer`](super::integer), for `Vec<Snowflake<T>>`.
+    pub mod vec {
+        use serde::{Deserialize, Deserializer, Serializer};
+
+        use super::{Entity, Integer, Snowflake};
+
+        /// Serializes a list of snowflakes as a list of unsigned integers.
+        ///
+        /// # Errors
+        ///
+        /// Returns an error if the serializer fails.
+        pub fn serialize<T: Entity, S: Serializer>(
+            ids: &[Snowflake<T>],
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            serializer.collect_seq(ids.iter().copied().map(Integer))
+        }
+
+        /// Deserializes a list of snowflakes from a list of unsigned integers.
+        ///
+        /// # Errors
+        ///
+        /// Returns an error if any of the values is not a non-zero integer.
+        pub fn deserialize<'de, T: Entity, D: Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Vec<Snowflake<T>>, D::Error> {
+            let ids = Vec::<Integer<T>>::deserialize(deserializer)?;
+            Ok(ids.into_iter().map(|Integer(id)| id).collect())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::{Deserialize, Serialize};
+    use serde_json::json;
+
+    use time::{Duration, Month, OffsetDateTime, UtcOffset};
+
+    use super::{DISCORD_EPOCH, Entity, GuildId, MAX_TIMESTAMP, NonZeroU64, Snowflake, UserId};
+
+    fn id<T: Entity>(n: u64) -> Snowflake<T> {
+        Snowflake::new_nonzero(NonZeroU64::new(n).expect("non-zero snowflake"))
+    }
+
+    #[test]
+    fn deserializes_strings_and_integers() {
+        let expected = id(80_351_110_224_678_912);
+
+        let from_str: UserId =
+            serde_json::from_value(json!("80351110224678912")).expect("string snowflake");
+        let from_int: UserId =
+            serde_json::from_value(json!(80_351_110_224_678_912_u64)).expect("integer snowflake");
+
+        assert_eq!(from_str, expected);
+        assert_eq!(from_int, expected);
+    }
+
+    #[test]
+    fn rejects_invalid_snowflakes() {
+        for value in [
+            json!(0),
+            json!(-1),
+            json!("0"),
+            json!("-1"),
+            json!("abc"),
+            json!(1.5),
+        ] {
+            assert!(
+                serde_json::from_value::<UserId>(value.clone()).is_err(),
+                "{value} must be rejected",
+            );
+        }
+    }
+
+    #[test]
+    fn serializes_as_string_by_default() {
+        let id: UserId = id(42);
+        assert_eq!(serde_json::to_value(id).expect("serialize"), json!("42"));
+    }
+
+    #[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+    struct Stored {
+        #[serde(with = "super::integer")]
+        id: GuildId,
+        #[serde(with = "super::integer::option")]
+        owner: Option<UserId>,
+        #[serde(with = "super::integer::option")]
+        missing: Option<UserId>,
+        #[serde(with = "super::integer::vec")]
+        members: Vec<UserId>,
+    }
+
+    #[test]
+    fn integer_round_trip() {
+        let stored = Stored {
+            id: id(1),
+            owner: Some(id(2)),
+            missing: None,
+            members: vec![id(3), id(4)],
+        };
+        let value = json!({ "id": 1, "owner": 2, "missing": null, "members": [3, 4] });
+
+        assert_eq!(serde_json::to_value(&stored).expect("serialize"), value);
+        assert_eq!(
+            serde_json::from_value::<Stored>(value).expect("deserialize"),
+            stored,
+        );
+    }
+
+    #[test]
+    fn integer_rejects_zero() {
+        let value = json!({ "id": 0, "owner": null, "missing": null, "members": [] });
+        assert!(serde_json::from_value::<Stored>(value).is_err());
+    }
+
+    #[test]
+    fn integer_round_trip_without_self_description() {
+        let stored = Stored {
+            id: id(1),
+            owner: Some(id(2)),
+            missing: None,
+            members: vec![id(3), id(4)],
+        };
+
+        let bytes = bincode::serialize(&stored).expect("serialize");
+        assert_eq!(
+            bincode::deserialize::<Stored>(&bytes).expect("deserialize"),
+            stored,
+        );
+    }
+
+    /// Example snowflake from Discord's documentation.
+    const DOCUMENTED: u64 = 175_928_847_299_117_063;
+
+    #[test]
+    fn decodes_documented_snowflake() {
+        let snowflake: UserId = id(DOCUMENTED);
+        let timestamp = snowflake.timestamp();
+
+        assert_eq!(
+            (timestamp.year(), timestamp.month(), timestamp.day()),
+            (2016, Month::April, 30),
+        );
+        assert_eq!(
+            (timestamp.hour(), timestamp.minute(), timestamp.second()),
+            (11, 18, 25),
+        );
+        assert_eq!(timestamp.millisecond(), 796);
+        assert_eq!(timestamp.offset(), UtcOffset::UTC);
+        assert_eq!(snowflake.worker_id(), 1);
+        assert_eq!(snowflake.process_id(), 0);
+        assert_eq!(snowflake.increment(), 7);
+    }
+
+    #[test]
+    fn from_timestamp_round_trip() {
+        let snowflake: UserId = id(DOCUMENTED);
+        let lower = UserId::from_timestamp(snowflake.timestamp()).expect("in range");
+
+        assert_eq!(lower.timestamp(), snowflake.timestamp());
+        assert_eq!(lower.get(), DOCUMENTED & !0x3F_FFFF);
+        assert!(lower <= snowflake);
+    }
+
+    #[test]
+    fn from_timestamp_bounds() {
+        let epoch = OffsetDateTime::UNIX_EPOCH + Duration::milliseconds(DISCORD_EPOCH);
+        let max = epoch + Duration::milliseconds(MAX_TIMESTAMP.cast_signed());
+
+        assert_eq!(UserId::from_timestamp(epoch), None);
+        assert_eq!(
+            UserId::from_timestamp(epoch + Duration::MILLISECOND).map(Snowflake::get),
+            Some(1 << 22),
+        );
+        assert_eq!(
+            UserId::from_timestamp(max).map(Snowflake::get),
+            Some(MAX_TIMESTAMP << 22),
+        );
+        assert_eq!(UserId::from_timestamp(max + Duration::MILLISECOND), None);
+    }
+
+    #[test]
+    fn parses_and_displays() {
+        let snowflake: UserId = "175928847299117063".parse().expect("valid snowflake");
+
+        assert_eq!(snowflake.get(), DOCUMENTED);
+        assert_eq!(snowflake.to_string(), "175928847299117063");
+        assert!("0".parse::<UserId>().is_err());
+        assert!("".parse::<UserId>().is_err());
+        assert!("12a".parse::<UserId>().is_err());
+        assert!("-1".parse::<UserId>().is_err());
     }
 }
@@ -0,0 +1,194 @@
+use core::fmt;
+use std::{fmt::Display, str::FromStr};
+
+use serde::de::{self, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Prefix of the hashes of animated images.
+const ANIMATED_PREFIX: &str = "a_";
+
+/// Hash of an image on Discord's CDN, such as an avatar or a guild icon.
+///
+/// Discord sends these as 32 hexadecimal digits, prefixed with `a_` if the image is
+/// animated. They are stored as their 16 bytes instead.
+///
+/// See [`cdn`](crate::cdn) to build the URL of an image from its hash.
+///
+/// <https://discord.com/developers/docs/reference#image-formatting>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ImageHash {
+    bytes: [u8; 16],
+    animated: bool,
+}
+
+impl ImageHash {
+    #[must_use]
+    pub const fn new(bytes: [u8; 16], animated: bool) -> Self {
+        Self { bytes, animated }
+    }
+
+    #[must_use]
+    pub const fn bytes(self) -> [u8; 16] {
+        self.bytes
+    }
+
+    /// Whether the image is animated, in which case it is also available as a GIF.
+    #[must_use]
+    pub const fn is_animated(self) -> bool {
+        self.animated
+    }
+}
+
+impl Display for ImageHash {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.animated {
+            f.write_str(ANIMATED_PREFIX)?;
+        }
+        for byte in self.bytes {
+            write!(f, "{byte:02x}")?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for ImageHash {
+    type Err = ImageHashParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (hex, animated) = s
+            .strip_prefix(ANIMATED_PREFIX)
+            .map_or((s, false), |hex| (hex, true));
+
+        if hex.len() != 32 {
+            return Err(ImageHashParseError::Length(hex.len()));
+        }
+
+        let mut bytes = [0; 16];
+        for (byte, digits) in bytes.iter_mut().zip(hex.as_bytes().chunks_exact(2)) {
+            *byte = (hex_digit(digits[0])? << 4) | hex_digit(digits[1])?;
+        }
+
+        Ok(Self { bytes, animated })
+    }
+}
+
+fn hex_digit(c: u8) -> Result<u8, ImageHashParseError> {
+    match c {
+        b'0'..=b'9' => Ok(c - b'0'),
+        b'a'..=b'f' => Ok(c - b'a' + 10),
+        b'A'..=b'F' => Ok(c - b'A' + 10),
+        _ => Err(ImageHashParseError::Digit(char::from(c))),
+    }
+}
+
+/// Error returned when parsing an [`ImageHash`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImageHashParseError {
+    /// The hash does not have 32 digits, excluding the `a_` prefix.
+    Length(usize),
+    /// The hash contains a character that is not a hexadecimal digit.
+    Digit(char),
+}
+
+impl Display for ImageHashParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Length(len) => write!(f, "image hash has {len} digits, expected 32"),
+            Self::Digit(c) => write!(f, "image hash contains non-hexadecimal character {c:?}"),
+        }
+    }
+}
+
+impl std::error::Error for ImageHashParseError {}
+
+impl Serialize for ImageHash {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ImageHash {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_str(ImageHashVisitor)
+    }
+}
+
+struct ImageHashVisitor;
+
+impl Visitor<'_> for ImageHashVisitor {
+    type Value = ImageHash;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("an image hash")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        v.parse().map_err(E::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ImageHash, ImageHashParseError};
+
+    const HEX: &str = "8342729096ea3675442027381ff50dfe";
+
+    #[test]
+    fn parses_and_displays() {
+        let hash: ImageHash = HEX.parse().expect("static hash");
+
+        assert!(!hash.is_animated());
+        assert_eq!(hash.bytes()[0], 0x83);
+        assert_eq!(hash.bytes()[15], 0xfe);
+        assert_eq!(hash.to_string(), HEX);
+    }
+
+    #[test]
+    fn animated_prefix() {
+        let animated: ImageHash = format!("a_{HEX}").parse().expect("animated hash");
+
+        assert!(animated.is_animated());
+        assert_eq!(animated.to_string(), format!("a_{HEX}"));
+        assert_eq!(
+            animated.bytes(),
+            HEX.parse::<ImageHash>().expect("hash").bytes()
+        );
+    }
+
+    #[test]
+    fn uppercase_digits_display_lowercase() {
+        let hash: ImageHash = HEX.to_uppercase().parse().expect("uppercase hash");
+        assert_eq!(hash.to_string(), HEX);
+    }
+
+    #[test]
+    fn rejects_wrong_length() {
+        assert_eq!(
+            HEX[..30].parse::<ImageHash>(),
+            Err(ImageHashParseError::Length(30)),
+        );
+        assert_eq!(
+            format!("a_{HEX}0").parse::<ImageHash>(),
+            Err(ImageHashParseError::Length(33)),
+        );
+        assert_eq!(
+            "a_".parse::<ImageHash>(),
+            Err(ImageHashParseError::Length(0))
+        );
+    }
+
+    #[test]
+    fn rejects_non_hex_digits() {
+        let hash = format!("{}g", &HEX[..31]);
+        assert_eq!(
+            hash.parse::<ImageHash>(),
+            Err(ImageHashParseError::Digit('g')),
+        );
+    }
+}
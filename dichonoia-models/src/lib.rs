@@ -1,5 +1,19 @@
+#[macro_use]
+mod macros;
+
+mod content;
+
 pub mod application;
+pub mod cdn;
+pub mod channel;
+pub mod emoji;
 pub mod gateway;
 pub mod guild;
 pub mod id;
+pub mod image_hash;
+pub mod message;
+pub mod soundboard;
+pub mod stage_instance;
+pub mod sticker;
 pub mod user;
+pub mod voice;
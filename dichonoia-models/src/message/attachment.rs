@@ -0,0 +1,70 @@
+use bitflags::bitflags;
+use serde::{Deserialize, Serialize};
+
+use crate::id::AttachmentId;
+
+/// Attachment Object
+///
+/// <https://discord.com/developers/docs/resources/message#attachment-object>
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Attachment {
+    /// Attachment ID
+    pub id: AttachmentId,
+    /// Name of file attached
+    pub filename: String,
+    /// Title of the file
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// Description for the file (max 1024 characters)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// The attachment's media type
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content_type: Option<String>,
+    /// Size of file in bytes
+    pub size: u64,
+    /// Source URL of file
+    pub url: String,
+    /// A proxied URL of file
+    pub proxy_url: String,
+    /// Height of file (if image)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub height: Option<u32>,
+    /// Width of file (if image)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub width: Option<u32>,
+    /// Whether this attachment is ephemeral
+    #[serde(default)]
+    pub ephemeral: bool,
+    /// Duration of the audio file (currently for voice messages)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub duration_secs: Option<f64>,
+    /// Base64 encoded bytearray representing a sampled waveform (currently for voice
+    /// messages)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub waveform: Option<String>,
+    /// [Attachment flags](AttachmentFlags)
+    #[serde(default)]
+    pub flags: AttachmentFlags,
+}
+
+bitflags! {
+    /// Attachment Flags
+    ///
+    /// <https://discord.com/developers/docs/resources/message#attachment-object-attachment-flags>
+    #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+    pub struct AttachmentFlags: u32 {
+        /// This attachment is a Clip from a stream
+        const IS_CLIP = 1 << 0;
+        /// This attachment is the thumbnail of a thread in a media channel
+        const IS_THUMBNAIL = 1 << 1;
+        /// This attachment has been edited using the remix feature on mobile (deprecated)
+        const IS_REMIX = 1 << 2;
+        /// This attachment was marked as a spoiler
+        const IS_SPOILER = 1 << 3;
+        /// This attachment is an animated image
+        const IS_ANIMATED = 1 << 5;
+    }
+}
+
+bitflags_serde!(AttachmentFlags);
@@ -0,0 +1,250 @@
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::Value;
+
+use crate::{
+    channel::ChannelType,
+    content::Content,
+    emoji::Emoji,
+    id::{ChannelId, RoleId, UserId},
+};
+
+/// Defines [`Component`] and [`ComponentType`] from the type of each component and its
+/// model, so that both always stay in sync.
+macro_rules! components {
+    ( $( $(#[$meta:meta])* $variant:ident($ty:ty) = $value:literal ),+ $(,)? ) => {
+        /// Component Object
+        ///
+        /// Interactive or layout element of a message, split by its
+        /// [type](ComponentType).
+        ///
+        /// <https://discord.com/developers/docs/components/reference#component-object>
+        #[derive(Debug, Clone)]
+        pub enum Component {
+            $( $(#[$meta])* $variant($ty), )+
+            /// Component of a type that is not modeled by this library, with its raw data.
+            Unknown {
+                /// Raw type of this component
+                kind: u8,
+                /// Raw data of this component
+                data: Value,
+            },
+        }
+
+        int_enum! {
+            /// Component Types
+            ///
+            /// <https://discord.com/developers/docs/components/reference#component-object-component-types>
+            pub enum ComponentType: u8 {
+                $( $(#[$meta])* $variant = $value, )+
+            }
+        }
+
+        impl Component {
+            /// Type of this component, or `None` if it is not modeled by this library.
+            #[must_use]
+            pub const fn kind(&self) -> Option<ComponentType> {
+                match self {
+                    $( Self::$variant(_) => Some(ComponentType::$variant), )+
+                    Self::Unknown { .. } => None,
+                }
+            }
+        }
+
+        impl Serialize for Component {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                match self {
+                    $(
+                        Self::$variant(component) => TaggedComponent {
+                            kind: ComponentType::$variant,
+                            component,
+                        }
+                        .serialize(serializer),
+                    )+
+                    Self::Unknown { data, .. } => data.serialize(serializer),
+                }
+            }
+        }
+
+        impl<'de> Deserialize<'de> for Component {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                let content = Content::deserialize(deserializer)?;
+                let kind = content.required_field("type")?;
+
+                match kind {
+                    $( $value => content.deserialize_into().map(Self::$variant), )+
+                    _ => Ok(Self::Unknown {
+                        kind,
+                        data: content.deserialize_into()?,
+                    }),
+                }
+            }
+        }
+    };
+}
+
+components! {
+    /// Container to display a row of interactive components
+    ActionRow(ActionRow) = 1,
+    /// Button object
+    Button(Button) = 2,
+    /// Select menu for picking from defined text options
+    StringSelect(SelectMenu) = 3,
+    /// Select menu for users
+    UserSelect(SelectMenu) = 5,
+    /// Select menu for roles
+    RoleSelect(SelectMenu) = 6,
+    /// Select menu for mentionables (users and roles)
+    MentionableSelect(SelectMenu) = 7,
+    /// Select menu for channels
+    ChannelSelect(SelectMenu) = 8,
+    /// Markdown text
+    TextDisplay(TextDisplay) = 10,
+}
+
+/// Component as it is sent by Discord, with its type next to its fields.
+#[derive(Serialize)]
+struct TaggedComponent<'a, T> {
+    #[serde(rename = "type")]
+    kind: ComponentType,
+    #[serde(flatten)]
+    component: &'a T,
+}
+
+/// Action Row
+///
+/// <https://discord.com/developers/docs/components/reference#action-row>
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ActionRow {
+    /// Optional identifier for component
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<u32>,
+    /// Up to 5 interactive button components or a single select component
+    pub components: Vec<Component>,
+}
+
+/// Button
+///
+/// <https://discord.com/developers/docs/components/reference#button>
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Button {
+    /// Optional identifier for component
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<u32>,
+    /// A [button style](ButtonStyle)
+    pub style: ButtonStyle,
+    /// Text that appears on the button; max 80 characters
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+    /// Partial emoji that appears on the button
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub emoji: Option<Emoji>,
+    /// Developer-defined identifier for the button; max 100 characters
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub custom_id: Option<String>,
+    /// URL for link buttons
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    /// Whether the button is disabled
+    #[serde(default)]
+    pub disabled: bool,
+}
+
+int_enum! {
+    /// Button Styles
+    ///
+    /// <https://discord.com/developers/docs/components/reference#button-button-styles>
+    pub enum ButtonStyle: u8 {
+        Primary = 1,
+        Secondary = 2,
+        Success = 3,
+        Danger = 4,
+        Link = 5,
+        Premium = 6,
+    }
+}
+
+/// Select Menu, shared by every kind of select component
+///
+/// <https://discord.com/developers/docs/components/reference#string-select>
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SelectMenu {
+    /// Optional identifier for component
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<u32>,
+    /// ID for the select menu; max 100 characters
+    pub custom_id: String,
+    /// Specified choices in a string select menu; max 25
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub options: Vec<SelectOption>,
+    /// Channel types to include in a channel select menu
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub channel_types: Vec<ChannelType>,
+    /// Placeholder text if nothing is selected; max 150 characters
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub placeholder: Option<String>,
+    /// Minimum number of items that must be chosen (defaults to 1); min 0, max 25
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_values: Option<u8>,
+    /// Maximum number of items that can be chosen (defaults to 1); max 25
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_values: Option<u8>,
+    /// Whether select menu is disabled
+    #[serde(default)]
+    pub disabled: bool,
+    /// Values selected by default in an auto-populated select menu; their number must
+    /// be between `min_values` and `max_values`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub default_values: Vec<SelectDefaultValue>,
+}
+
+/// Select Default Value
+///
+/// User, role or channel selected by default in an auto-populated select menu. Discord
+/// sends this as an `id` and a `type` field, where the type tells which kind of ID it is.
+///
+/// <https://discord.com/developers/docs/components/reference#user-select-select-default-value-structure>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum SelectDefaultValue {
+    User { id: UserId },
+    Role { id: RoleId },
+    Channel { id: ChannelId },
+}
+
+/// Select Option
+///
+/// <https://discord.com/developers/docs/components/reference#string-select-select-option-structure>
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SelectOption {
+    /// User-facing name of the option; max 100 characters
+    pub label: String,
+    /// Developer-defined value of the option; max 100 characters
+    pub value: String,
+    /// Additional description of the option; max 100 characters
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Partial emoji shown next to the option
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub emoji: Option<Emoji>,
+    /// Will show this option as selected by default
+    #[serde(default)]
+    pub default: bool,
+}
+
+/// Text Display
+///
+/// <https://discord.com/developers/docs/components/reference#text-display>
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TextDisplay {
+    /// Optional identifier for component
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<u32>,
+    /// Text that will be displayed similar to a message
+    pub content: String,
+}
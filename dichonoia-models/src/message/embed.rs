@@ -0,0 +1,149 @@
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+
+/// Embed Object
+///
+/// <https://discord.com/developers/docs/resources/message#embed-object>
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Embed {
+    /// Title of embed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// Type of embed (always "rich" for webhook embeds)
+    #[serde(rename = "type", default, skip_serializing_if = "Option::is_none")]
+    pub kind: Option<String>,
+    /// Description of embed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// URL of embed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    /// Timestamp of embed content
+    #[serde(
+        default,
+        with = "time::serde::rfc3339::option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub timestamp: Option<OffsetDateTime>,
+    /// Color code of the embed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub color: Option<u32>,
+    /// Footer information
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub footer: Option<EmbedFooter>,
+    /// Image information
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub image: Option<EmbedImage>,
+    /// Thumbnail information
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub thumbnail: Option<EmbedImage>,
+    /// Video information
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub video: Option<EmbedVideo>,
+    /// Provider information
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub provider: Option<EmbedProvider>,
+    /// Author information
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub author: Option<EmbedAuthor>,
+    /// Fields information, max of 25
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub fields: Vec<EmbedField>,
+}
+
+/// Embed Footer Object
+///
+/// <https://discord.com/developers/docs/resources/message#embed-object-embed-footer-structure>
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EmbedFooter {
+    /// Footer text
+    pub text: String,
+    /// URL of footer icon (only supports http(s) and attachments)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub icon_url: Option<String>,
+    /// A proxied URL of footer icon
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub proxy_icon_url: Option<String>,
+}
+
+/// Embed Image and Thumbnail Object
+///
+/// <https://discord.com/developers/docs/resources/message#embed-object-embed-image-structure>
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EmbedImage {
+    /// Source URL of image (only supports http(s) and attachments)
+    pub url: String,
+    /// A proxied URL of the image
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub proxy_url: Option<String>,
+    /// Height of image
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub height: Option<u32>,
+    /// Width of image
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub width: Option<u32>,
+}
+
+/// Embed Video Object
+///
+/// <https://discord.com/developers/docs/resources/message#embed-object-embed-video-structure>
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EmbedVideo {
+    /// Source URL of video
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    /// A proxied URL of the video
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub proxy_url: Option<String>,
+    /// Height of video
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub height: Option<u32>,
+    /// Width of video
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub width: Option<u32>,
+}
+
+/// Embed Provider Object
+///
+/// <https://discord.com/developers/docs/resources/message#embed-object-embed-provider-structure>
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EmbedProvider {
+    /// Name of provider
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// URL of provider
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+/// Embed Author Object
+///
+/// <https://discord.com/developers/docs/resources/message#embed-object-embed-author-structure>
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EmbedAuthor {
+    /// Name of author
+    pub name: String,
+    /// URL of author (only supports http(s))
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    /// URL of author icon (only supports http(s) and attachments)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub icon_url: Option<String>,
+    /// A proxied URL of author icon
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub proxy_icon_url: Option<String>,
+}
+
+/// Embed Field Object
+///
+/// <https://discord.com/developers/docs/resources/message#embed-object-embed-field-structure>
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EmbedField {
+    /// Name of the field
+    pub name: String,
+    /// Value of the field
+    pub value: String,
+    /// Whether or not this field should display inline
+    #[serde(default)]
+    pub inline: bool,
+}
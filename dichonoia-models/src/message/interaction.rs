@@ -0,0 +1,144 @@
+use std::borrow::Cow;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{
+    id::{GuildId, InteractionId, MessageId, UserId},
+    user::User,
+};
+
+/// Message Interaction Metadata Object
+///
+/// Metadata about the interaction, including the source of the interaction and relevant
+/// server and user IDs.
+///
+/// <https://discord.com/developers/docs/resources/message#message-interaction-metadata-object>
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MessageInteractionMetadata {
+    /// ID of the interaction
+    pub id: InteractionId,
+    /// [Type of interaction](InteractionType)
+    #[serde(rename = "type")]
+    pub kind: InteractionType,
+    /// User who triggered the interaction
+    pub user: User,
+    /// Installation contexts the interaction was authorized for
+    pub authorizing_integration_owners: AuthorizingIntegrationOwners,
+    /// ID of the original response message, present only on follow-up messages
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub original_response_message_id: Option<MessageId>,
+    /// ID of the message that contained the interactive component, present only on
+    /// messages created from component interactions
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub interacted_message_id: Option<MessageId>,
+    /// User the command was run on, present only on user command interactions
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target_user: Option<User>,
+    /// ID of the message the command was run on, present only on message command
+    /// interactions
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target_message_id: Option<MessageId>,
+    /// Metadata of the interaction that opened the modal, present only on modal submit
+    /// interactions
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub triggering_interaction_metadata: Option<Box<Self>>,
+}
+
+/// Authorizing Integration Owners Object
+///
+/// Owner of each installation context an interaction was authorized for, keyed by
+/// [application integration type].
+///
+/// <https://discord.com/developers/docs/interactions/receiving-and-responding#interaction-object-authorizing-integration-owners-object>
+///
+/// [application integration type]: https://discord.com/developers/docs/resources/application#application-object-application-integration-types
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+pub struct AuthorizingIntegrationOwners {
+    /// Where the app installed to a guild was used, present if it is installed to one
+    #[serde(rename = "0", default, skip_serializing_if = "Option::is_none")]
+    pub guild_install: Option<GuildInstallContext>,
+    /// ID of the user who installed the app, present if it is installed to a user
+    #[serde(rename = "1", default, skip_serializing_if = "Option::is_none")]
+    pub user_install: Option<UserId>,
+}
+
+/// Where an app installed to a guild was used.
+///
+/// Discord sends the ID of the guild, or `"0"` if the interaction was triggered from the
+/// DM of the app's bot user.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GuildInstallContext {
+    Guild(GuildId),
+    BotDm,
+}
+
+impl Serialize for GuildInstallContext {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Self::Guild(id) => id.serialize(serializer),
+            Self::BotDm => serializer.serialize_str("0"),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for GuildInstallContext {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        match Cow::<'de, str>::deserialize(deserializer)? {
+            id if id == "0" => Ok(Self::BotDm),
+            id => id
+                .parse()
+                .map(Self::Guild)
+                .map_err(serde::de::Error::custom),
+        }
+    }
+}
+
+int_enum! {
+    /// Interaction Type
+    ///
+    /// <https://discord.com/developers/docs/interactions/receiving-and-responding#interaction-object-interaction-type>
+    pub enum InteractionType: u8 {
+        Ping = 1,
+        ApplicationCommand = 2,
+        MessageComponent = 3,
+        ApplicationCommandAutocomplete = 4,
+        ModalSubmit = 5,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::{AuthorizingIntegrationOwners, GuildInstallContext};
+    use crate::id::Snowflake;
+
+    #[test]
+    fn authorizing_integration_owners() {
+        let guild: AuthorizingIntegrationOwners =
+            serde_json::from_value(json!({ "0": "41771983423143937" })).expect("guild install");
+        let dm: AuthorizingIntegrationOwners =
+            serde_json::from_value(json!({ "0": "0", "1": "80351110224678912" })).expect("bot DM");
+
+        assert!(matches!(
+            guild.guild_install,
+            Some(GuildInstallContext::Guild(id)) if id.get() == 41_771_983_423_143_937
+        ));
+        assert_eq!(guild.user_install, None);
+        assert_eq!(dm.guild_install, Some(GuildInstallContext::BotDm));
+        assert_eq!(
+            dm.user_install.map(Snowflake::get),
+            Some(80_351_110_224_678_912)
+        );
+        assert_eq!(
+            serde_json::to_value(dm).expect("serializable"),
+            json!({ "0": "0", "1": "80351110224678912" }),
+        );
+    }
+}
@@ -0,0 +1,369 @@
+pub mod attachment;
+pub mod component;
+pub mod embed;
+pub mod interaction;
+pub mod poll;
+pub mod reaction;
+
+pub use attachment::Attachment;
+pub use component::Component;
+pub use embed::Embed;
+pub use interaction::{AuthorizingIntegrationOwners, MessageInteractionMetadata};
+pub use poll::Poll;
+pub use reaction::Reaction;
+
+use bitflags::bitflags;
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+
+use crate::{
+    channel::{Channel, ChannelType},
+    guild::member::Member,
+    id::{
+        ApplicationId, ChannelId, GuildId, MessageId, RoleId, RoleSubscriptionListingId, UserId,
+        WebhookId,
+    },
+    sticker::StickerItem,
+    user::User,
+};
+
+/// Message Object
+///
+/// Represents a message sent in a channel within Discord.
+///
+/// <https://discord.com/developers/docs/resources/message#message-object>
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Message {
+    /// ID of the message
+    pub id: MessageId,
+    /// ID of the channel the message was sent in
+    pub channel_id: ChannelId,
+    /// Author of this message
+    pub author: User,
+    /// Contents of the message
+    pub content: String,
+    /// When this message was sent
+    #[serde(with = "time::serde::rfc3339")]
+    pub timestamp: OffsetDateTime,
+    /// When this message was edited, or `None` if never
+    #[serde(with = "time::serde::rfc3339::option")]
+    pub edited_timestamp: Option<OffsetDateTime>,
+    /// Whether this was a TTS message
+    pub tts: bool,
+    /// Whether this message mentions everyone
+    pub mention_everyone: bool,
+    /// Users specifically mentioned in the message
+    pub mentions: Vec<User>,
+    /// Roles specifically mentioned in this message
+    pub mention_roles: Vec<RoleId>,
+    /// Channels specifically mentioned in this message
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub mention_channels: Vec<ChannelMention>,
+    /// Any attached files
+    pub attachments: Vec<Attachment>,
+    /// Any embedded content
+    pub embeds: Vec<Embed>,
+    /// Reactions to the message
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub reactions: Vec<Reaction>,
+    /// Used for validating a message was sent
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nonce: Option<Nonce>,
+    /// Whether this message is pinned
+    pub pinned: bool,
+    /// If the message is generated by a webhook, this is the webhook's ID
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub webhook_id: Option<WebhookId>,
+    /// [Type of message](MessageType)
+    #[serde(rename = "type")]
+    pub kind: MessageType,
+    /// Sent with Rich Presence-related chat embeds
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub activity: Option<MessageActivity>,
+    /// If the message is an Interaction or application-owned webhook, this is the ID of
+    /// the application
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub application_id: Option<ApplicationId>,
+    /// [Message flags](MessageFlags)
+    #[serde(default)]
+    pub flags: MessageFlags,
+    /// Data showing the source of a crosspost, channel follow add, pin, or reply message
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub message_reference: Option<MessageReference>,
+    /// Messages associated with the `message_reference`, when it is a forward
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub message_snapshots: Vec<MessageSnapshot>,
+    /// Message associated with the `message_reference`; `None` if the referenced message
+    /// was deleted, or if it was not loaded
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub referenced_message: Option<Box<Self>>,
+    /// Sent if the message is sent as a result of an interaction
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub interaction_metadata: Option<MessageInteractionMetadata>,
+    /// Thread that was started from this message
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub thread: Option<Channel>,
+    /// Components like buttons, action rows, or other interactive components
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub components: Vec<Component>,
+    /// Stickers sent with the message
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub sticker_items: Vec<StickerItem>,
+    /// Approximate position of the message in a thread
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub position: Option<u32>,
+    /// Data of the role subscription purchase or renewal that prompted this
+    /// `RoleSubscriptionPurchase` message
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub role_subscription_data: Option<RoleSubscriptionData>,
+    /// Poll attached to the message
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub poll: Option<Poll>,
+    /// ID of the guild the message was sent in, when received over the gateway
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub guild_id: Option<GuildId>,
+    /// Member properties of the author, when received over the gateway in a guild
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub member: Option<Member>,
+    /// Call associated with the message
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub call: Option<MessageCall>,
+}
+
+/// Message Activity Structure
+///
+/// <https://discord.com/developers/docs/resources/message#message-object-message-activity-structure>
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MessageActivity {
+    /// [Type of message activity](MessageActivityType)
+    #[serde(rename = "type")]
+    pub kind: MessageActivityType,
+    /// `party_id` from a Rich Presence event
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub party_id: Option<String>,
+}
+
+int_enum! {
+    /// Message Activity Types
+    ///
+    /// <https://discord.com/developers/docs/resources/message#message-object-message-activity-types>
+    pub enum MessageActivityType: u8 {
+        Join = 1,
+        Spectate = 2,
+        Listen = 3,
+        JoinRequest = 5,
+    }
+}
+
+/// Message Snapshot Object
+///
+/// <https://discord.com/developers/docs/resources/message#message-snapshot-object>
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MessageSnapshot {
+    /// Minimal subset of the fields of the forwarded message
+    pub message: SnapshotMessage,
+}
+
+/// Forwarded message of a [`MessageSnapshot`], with only the fields that are kept.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SnapshotMessage {
+    /// [Type of message](MessageType)
+    #[serde(rename = "type")]
+    pub kind: MessageType,
+    /// Contents of the message
+    pub content: String,
+    /// Any embedded content
+    pub embeds: Vec<Embed>,
+    /// Any attached files
+    pub attachments: Vec<Attachment>,
+    /// When this message was sent
+    #[serde(with = "time::serde::rfc3339")]
+    pub timestamp: OffsetDateTime,
+    /// When this message was edited, or `None` if never
+    #[serde(with = "time::serde::rfc3339::option")]
+    pub edited_timestamp: Option<OffsetDateTime>,
+    /// [Message flags](MessageFlags)
+    #[serde(default)]
+    pub flags: MessageFlags,
+    /// Users specifically mentioned in the message
+    pub mentions: Vec<User>,
+    /// Roles specifically mentioned in this message
+    pub mention_roles: Vec<RoleId>,
+    /// Stickers sent with the message
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub sticker_items: Vec<StickerItem>,
+    /// Components like buttons, action rows, or other interactive components
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub components: Vec<Component>,
+}
+
+/// Role Subscription Data Object
+///
+/// <https://discord.com/developers/docs/resources/message#role-subscription-data-object>
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RoleSubscriptionData {
+    /// ID of the SKU and listing that the user is subscribed to
+    pub role_subscription_listing_id: RoleSubscriptionListingId,
+    /// Name of the tier that the user is subscribed to
+    pub tier_name: String,
+    /// Cumulative number of months that the user has been subscribed for
+    pub total_months_subscribed: u32,
+    /// Whether this notification is for a renewal rather than a new purchase
+    pub is_renewal: bool,
+}
+
+/// Message Call Object
+///
+/// <https://discord.com/developers/docs/resources/message#message-call-object>
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MessageCall {
+    /// IDs of the users that participated in the call
+    pub participants: Vec<UserId>,
+    /// When the call ended, or `None` if it is still ongoing
+    #[serde(default, with = "time::serde::rfc3339::option")]
+    pub ended_timestamp: Option<OffsetDateTime>,
+}
+
+/// Channel Mention Object
+///
+/// <https://discord.com/developers/docs/resources/message#channel-mention-object>
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChannelMention {
+    /// ID of the channel
+    pub id: ChannelId,
+    /// ID of the guild containing the channel
+    pub guild_id: GuildId,
+    /// [Type of channel](ChannelType)
+    #[serde(rename = "type")]
+    pub kind: ChannelType,
+    /// Name of the channel
+    pub name: String,
+}
+
+/// Value used for validating a message was sent, either an integer or a string.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum Nonce {
+    Integer(i64),
+    String(String),
+}
+
+/// Message Reference Object
+///
+/// <https://discord.com/developers/docs/resources/message#message-reference-structure>
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MessageReference {
+    /// [Type of reference](MessageReferenceType)
+    #[serde(rename = "type", default)]
+    pub kind: MessageReferenceType,
+    /// ID of the originating message
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub message_id: Option<MessageId>,
+    /// ID of the originating message's channel
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub channel_id: Option<ChannelId>,
+    /// ID of the originating message's guild
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub guild_id: Option<GuildId>,
+    /// When sending, whether to error if the referenced message doesn't exist instead of
+    /// sending as a normal (non-reply) message, defaults to `true`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fail_if_not_exists: Option<bool>,
+}
+
+int_enum! {
+    /// Message Reference Types
+    ///
+    /// <https://discord.com/developers/docs/resources/message#message-reference-types>
+    #[derive(Default)]
+    pub enum MessageReferenceType: u8 {
+        /// A standard reference used by replies
+        #[default]
+        Default = 0,
+        /// Reference used to point to a message at a point in time
+        Forward = 1,
+    }
+}
+
+int_enum! {
+    /// Message Types
+    ///
+    /// <https://discord.com/developers/docs/resources/message#message-object-message-types>
+    pub enum MessageType: u8 {
+        Default = 0,
+        RecipientAdd = 1,
+        RecipientRemove = 2,
+        Call = 3,
+        ChannelNameChange = 4,
+        ChannelIconChange = 5,
+        ChannelPinnedMessage = 6,
+        UserJoin = 7,
+        GuildBoost = 8,
+        GuildBoostTier1 = 9,
+        GuildBoostTier2 = 10,
+        GuildBoostTier3 = 11,
+        ChannelFollowAdd = 12,
+        GuildDiscoveryDisqualified = 14,
+        GuildDiscoveryRequalified = 15,
+        GuildDiscoveryGracePeriodInitialWarning = 16,
+        GuildDiscoveryGracePeriodFinalWarning = 17,
+        ThreadCreated = 18,
+        Reply = 19,
+        ChatInputCommand = 20,
+        ThreadStarterMessage = 21,
+        GuildInviteReminder = 22,
+        ContextMenuCommand = 23,
+        AutoModerationAction = 24,
+        RoleSubscriptionPurchase = 25,
+        InteractionPremiumUpsell = 26,
+        StageStart = 27,
+        StageEnd = 28,
+        StageSpeaker = 29,
+        StageTopic = 31,
+        GuildApplicationPremiumSubscription = 32,
+        GuildIncidentAlertModeEnabled = 36,
+        GuildIncidentAlertModeDisabled = 37,
+        GuildIncidentReportRaid = 38,
+        GuildIncidentReportFalseAlarm = 39,
+        PurchaseNotification = 44,
+        PollResult = 46,
+    }
+}
+
+bitflags! {
+    /// Message Flags
+    ///
+    /// <https://discord.com/developers/docs/resources/message#message-object-message-flags>
+    #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+    pub struct MessageFlags: u32 {
+        /// This message has been published to subscribed channels (via Channel Following)
+        const CROSSPOSTED = 1 << 0;
+        /// This message originated from a message in another channel (via Channel
+        /// Following)
+        const IS_CROSSPOST = 1 << 1;
+        /// Do not include any embeds when serializing this message
+        const SUPPRESS_EMBEDS = 1 << 2;
+        /// The source message for this crosspost has been deleted (via Channel Following)
+        const SOURCE_MESSAGE_DELETED = 1 << 3;
+        /// This message came from the urgent message system
+        const URGENT = 1 << 4;
+        /// This message has an associated thread, with the same ID as the message
+        const HAS_THREAD = 1 << 5;
+        /// This message is only visible to the user who invoked the Interaction
+        const EPHEMERAL = 1 << 6;
+        /// This message is an Interaction Response and the bot is "thinking"
+        const LOADING = 1 << 7;
+        /// This message failed to mention some roles and add their members to the thread
+        const FAILED_TO_MENTION_SOME_ROLES_IN_THREAD = 1 << 8;
+        /// This message will not trigger push and desktop notifications
+        const SUPPRESS_NOTIFICATIONS = 1 << 12;
+        /// This message is a voice message
+        const IS_VOICE_MESSAGE = 1 << 13;
+        /// This message has a snapshot (via Message Forwarding)
+        const HAS_SNAPSHOT = 1 << 14;
+        /// Allows you to create fully component-driven messages
+        const IS_COMPONENTS_V2 = 1 << 15;
+    }
+}
+
+bitflags_serde!(MessageFlags);
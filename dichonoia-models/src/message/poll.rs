@@ -0,0 +1,83 @@
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+
+use crate::emoji::Emoji;
+
+/// Poll Object
+///
+/// <https://discord.com/developers/docs/resources/poll#poll-object>
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Poll {
+    /// Question of the poll, only `text` is supported
+    pub question: PollMedia,
+    /// Each of the answers available in the poll
+    pub answers: Vec<PollAnswer>,
+    /// Time when the poll ends, or `None` for a poll that never expires
+    #[serde(with = "time::serde::rfc3339::option")]
+    pub expiry: Option<OffsetDateTime>,
+    /// Whether a user can select multiple answers
+    pub allow_multiselect: bool,
+    /// [Layout type](PollLayoutType) of the poll
+    pub layout_type: PollLayoutType,
+    /// Results of the poll
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub results: Option<PollResults>,
+}
+
+/// Poll Media Object
+///
+/// <https://discord.com/developers/docs/resources/poll#poll-media-object>
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PollMedia {
+    /// Text of the field
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+    /// Emoji of the field
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub emoji: Option<Emoji>,
+}
+
+/// Poll Answer Object
+///
+/// <https://discord.com/developers/docs/resources/poll#poll-answer-object>
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PollAnswer {
+    /// ID of the answer
+    pub answer_id: u32,
+    /// Data of the answer
+    pub poll_media: PollMedia,
+}
+
+/// Poll Results Object
+///
+/// <https://discord.com/developers/docs/resources/poll#poll-results-object>
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PollResults {
+    /// Whether the votes have been precisely counted
+    pub is_finalized: bool,
+    /// Counts for each answer
+    pub answer_counts: Vec<PollAnswerCount>,
+}
+
+/// Poll Answer Count Object
+///
+/// <https://discord.com/developers/docs/resources/poll#poll-results-object-poll-answer-count-object-structure>
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct PollAnswerCount {
+    /// `answer_id` of the answer
+    pub id: u32,
+    /// Number of votes for this answer
+    pub count: u32,
+    /// Whether the current user voted for this answer
+    pub me_voted: bool,
+}
+
+int_enum! {
+    /// Layout Type
+    ///
+    /// <https://discord.com/developers/docs/resources/poll#layout-type>
+    pub enum PollLayoutType: u8 {
+        /// The default layout type
+        Default = 1,
+    }
+}
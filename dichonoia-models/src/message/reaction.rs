@@ -0,0 +1,43 @@
+use serde::{Deserialize, Serialize};
+
+use crate::emoji::Emoji;
+
+/// Reaction Object
+///
+/// <https://discord.com/developers/docs/resources/message#reaction-object>
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Reaction {
+    /// Total number of times this emoji has been used to react (including super reacts)
+    pub count: u32,
+    /// [Reaction count details](ReactionCountDetails)
+    pub count_details: ReactionCountDetails,
+    /// Whether the current user reacted using this emoji
+    pub me: bool,
+    /// Whether the current user super-reacted using this emoji
+    pub me_burst: bool,
+    /// Emoji information, partial for custom emojis
+    pub emoji: Emoji,
+    /// HEX colors used for super reaction
+    pub burst_colors: Vec<String>,
+}
+
+/// Reaction Count Details Object
+///
+/// <https://discord.com/developers/docs/resources/message#reaction-count-details-object>
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct ReactionCountDetails {
+    /// Count of super reactions
+    pub burst: u32,
+    /// Count of normal reactions
+    pub normal: u32,
+}
+
+int_enum! {
+    /// Reaction Types
+    ///
+    /// <https://discord.com/developers/docs/resources/message#get-reactions-reaction-types>
+    pub enum ReactionType: u8 {
+        Normal = 0,
+        Burst = 1,
+    }
+}
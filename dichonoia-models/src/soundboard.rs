@@ -0,0 +1,34 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    id::{EmojiId, GuildId, SoundId},
+    user::User,
+};
+
+/// Soundboard Sound Object
+///
+/// Users can play soundboard sounds in voice channels, triggering a Voice Channel Effect
+/// Send Gateway event for users connected to the voice channel.
+///
+/// <https://discord.com/developers/docs/resources/soundboard#soundboard-sound-object>
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SoundboardSound {
+    /// Name of this sound
+    pub name: String,
+    /// ID of this sound
+    pub sound_id: SoundId,
+    /// Volume of this sound, from 0 to 1
+    pub volume: f64,
+    /// ID of this sound's custom emoji
+    pub emoji_id: Option<EmojiId>,
+    /// Unicode character of this sound's standard emoji
+    pub emoji_name: Option<String>,
+    /// ID of the guild this sound is in, or `None` for a default sound
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub guild_id: Option<GuildId>,
+    /// Whether this sound can be used, may be `false` due to loss of Server Boosts
+    pub available: bool,
+    /// User who created this sound
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user: Option<User>,
+}
@@ -0,0 +1,36 @@
+use serde::{Deserialize, Serialize};
+
+use crate::id::{ChannelId, GuildId, ScheduledEventId, StageInstanceId};
+
+/// Stage Instance Object
+///
+/// A Stage Instance holds information about a live stage.
+///
+/// <https://discord.com/developers/docs/resources/stage-instance#stage-instance-object>
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StageInstance {
+    /// ID of this Stage instance
+    pub id: StageInstanceId,
+    /// Guild ID of the associated Stage channel
+    pub guild_id: GuildId,
+    /// ID of the associated Stage channel
+    pub channel_id: ChannelId,
+    /// Topic of the Stage instance (1-120 characters)
+    pub topic: String,
+    /// [Privacy level](StagePrivacyLevel) of the Stage instance
+    pub privacy_level: StagePrivacyLevel,
+    /// ID of the scheduled event for this Stage instance
+    pub guild_scheduled_event_id: Option<ScheduledEventId>,
+}
+
+int_enum! {
+    /// Privacy Level
+    ///
+    /// <https://discord.com/developers/docs/resources/stage-instance#stage-instance-object-privacy-level>
+    pub enum StagePrivacyLevel: u8 {
+        /// The Stage instance is visible publicly (deprecated)
+        Public = 1,
+        /// The Stage instance is visible to only guild members
+        GuildOnly = 2,
+    }
+}
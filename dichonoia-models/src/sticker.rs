@@ -0,0 +1,86 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    id::{GuildId, StickerId, StickerPackId},
+    user::User,
+};
+
+/// Sticker Object
+///
+/// Represents a sticker that can be sent in messages.
+///
+/// <https://discord.com/developers/docs/resources/sticker#sticker-object>
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Sticker {
+    /// ID of the sticker
+    pub id: StickerId,
+    /// For standard stickers, ID of the pack the sticker is from
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pack_id: Option<StickerPackId>,
+    /// Name of the sticker
+    pub name: String,
+    /// Description of the sticker
+    pub description: Option<String>,
+    /// Autocomplete/suggestion tags for the sticker (max 200 characters)
+    pub tags: String,
+    /// [Type of sticker](StickerType)
+    #[serde(rename = "type")]
+    pub kind: StickerType,
+    /// [Type of sticker format](StickerFormatType)
+    pub format_type: StickerFormatType,
+    /// Whether this guild sticker can be used, may be `false` due to loss of Server Boosts
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub available: Option<bool>,
+    /// ID of the guild that owns this sticker
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub guild_id: Option<GuildId>,
+    /// User that uploaded the guild sticker
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user: Option<User>,
+    /// Standard sticker's sort order within its pack
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sort_value: Option<u32>,
+}
+
+/// Sticker Item Object
+///
+/// The smallest amount of data required to render a sticker.
+///
+/// <https://discord.com/developers/docs/resources/sticker#sticker-item-object>
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StickerItem {
+    /// ID of the sticker
+    pub id: StickerId,
+    /// Name of the sticker
+    pub name: String,
+    /// [Type of sticker format](StickerFormatType)
+    pub format_type: StickerFormatType,
+}
+
+int_enum! {
+    /// Sticker Types
+    ///
+    /// <https://discord.com/developers/docs/resources/sticker#sticker-object-sticker-types>
+    pub enum StickerType: u8 {
+        /// An official sticker in a pack
+        Standard = 1,
+        /// A sticker uploaded to a guild for the guild's members
+        Guild = 2,
+    }
+}
+
+int_enum! {
+    /// Sticker Format Types
+    ///
+    /// <https://discord.com/developers/docs/resources/sticker#sticker-object-sticker-format-types>
+    pub enum StickerFormatType: u8 {
+        /// PNG
+        Png = 1,
+        /// APNG
+        Apng = 2,
+        /// LOTTIE
+        Lottie = 3,
+        /// GIF
+        Gif = 4,
+    }
+}
@@ -1,20 +1,215 @@
 pub mod discriminator;
 
+use bitflags::bitflags;
 use serde::{Deserialize, Serialize};
 
-use crate::{id::UserId, user::discriminator::Discriminator};
+use crate::{
+    cdn::{self, CdnError, ImageFormat, ImageSize},
+    id::{GuildId, SkuId, UserId},
+    image_hash::ImageHash,
+    user::discriminator::Discriminator,
+};
 
-// TODO: Finish rest of the struct
+/// User Object
+///
+/// Users in Discord are generally considered the base entity. Fields only visible to the
+/// user themselves are in [`CurrentUser`].
+///
+/// <https://discord.com/developers/docs/resources/user#user-object>
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct User {
+    /// User's ID
     pub id: UserId,
+    /// User's username, not unique across the platform
     pub username: String,
+    /// User's Discord-tag, or `None` for a user who migrated to unique usernames
     #[serde(with = "crate::user::discriminator")]
     pub discriminator: Option<Discriminator>,
+    /// User's display name, if it is set. For bots, this is the application name
     pub global_name: Option<String>,
-    pub avatar: Option<String>,
+    /// User's avatar hash
+    pub avatar: Option<ImageHash>,
+    /// Whether the user belongs to an `OAuth2` application
     #[serde(skip_serializing_if = "Option::is_none")]
     pub bot: Option<bool>,
+    /// Whether the user is an Official Discord System user (part of the urgent message
+    /// system)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub system: Option<bool>,
+    /// User's banner hash
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub banner: Option<ImageHash>,
+    /// User's banner color encoded as an integer representation of hexadecimal color
+    /// code
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub accent_color: Option<u32>,
+    /// Public [flags](UserFlags) on a user's account
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub public_flags: Option<UserFlags>,
+    /// Data for the user's avatar decoration
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub avatar_decoration_data: Option<AvatarDecorationData>,
+    /// Data for the user's collectibles
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub collectibles: Option<Collectibles>,
+    /// User's primary guild
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub primary_guild: Option<PrimaryGuild>,
+}
+
+impl User {
+    /// URL of the avatar of the user, or of their default avatar if they have not set one.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `format` is not PNG, JPEG, WebP or GIF, or is GIF while the
+    /// avatar is not animated.
+    pub fn avatar_url(
+        &self,
+        format: ImageFormat,
+        size: Option<ImageSize>,
+    ) -> Result<String, CdnError> {
+        self.avatar.map_or_else(
+            || Ok(cdn::default_avatar(self.id, self.discriminator)),
+            |hash| cdn::avatar(self.id, hash, format, size),
+        )
+    }
+}
+
+/// User Object of the current user
+///
+/// Includes the fields that are only visible to the user themselves, such as in the
+/// [Ready] event.
+///
+/// <https://discord.com/developers/docs/resources/user#user-object>
+///
+/// [Ready]: crate::gateway::events::Ready
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CurrentUser {
+    /// Fields visible to other users
+    #[serde(flatten)]
+    pub user: User,
+    /// Whether the user has two factor enabled on their account
+    #[serde(default)]
+    pub mfa_enabled: bool,
+    /// User's chosen language option
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub locale: Option<String>,
+    /// Whether the email on this account has been verified
+    #[serde(default)]
+    pub verified: bool,
+    /// User's email
+    #[serde(default)]
+    pub email: Option<String>,
+    /// [Flags](UserFlags) on a user's account
+    #[serde(default)]
+    pub flags: UserFlags,
+    /// [Type of Nitro subscription](PremiumType) on a user's account
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub premium_type: Option<PremiumType>,
+}
+
+/// Avatar Decoration Data Object
+///
+/// <https://discord.com/developers/docs/resources/user#avatar-decoration-data-object>
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AvatarDecorationData {
+    /// Avatar decoration hash
+    pub asset: ImageHash,
+    /// ID of the avatar decoration's SKU
+    pub sku_id: SkuId,
+}
+
+/// Collectibles Object
+///
+/// <https://discord.com/developers/docs/resources/user#collectibles>
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Collectibles {
+    /// User's nameplate
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nameplate: Option<Nameplate>,
+}
+
+/// Nameplate Object
+///
+/// <https://discord.com/developers/docs/resources/user#nameplate>
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Nameplate {
+    /// ID of the nameplate SKU
+    pub sku_id: SkuId,
+    /// Path to the nameplate asset
+    pub asset: String,
+    /// Label of this nameplate
+    pub label: String,
+    /// Background color of the nameplate
+    pub palette: String,
+}
+
+/// User Primary Guild Object
+///
+/// <https://discord.com/developers/docs/resources/user#user-object-user-primary-guild>
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PrimaryGuild {
+    /// ID of the user's primary guild
+    pub identity_guild_id: Option<GuildId>,
+    /// Whether the user is displaying the primary guild's server tag. This can be `None`
+    /// if the system clears the identity, e.g. the server no longer supports tags
+    pub identity_enabled: Option<bool>,
+    /// Text of the user's server tag, limited to 4 characters
+    pub tag: Option<String>,
+    /// Server tag badge hash
+    pub badge: Option<ImageHash>,
+}
+
+bitflags! {
+    /// User Flags
+    ///
+    /// <https://discord.com/developers/docs/resources/user#user-object-user-flags>
+    #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+    pub struct UserFlags: u32 {
+        /// Discord Employee
+        const STAFF = 1 << 0;
+        /// Partnered Server Owner
+        const PARTNER = 1 << 1;
+        /// HypeSquad Events Member
+        const HYPESQUAD = 1 << 2;
+        /// Bug Hunter Level 1
+        const BUG_HUNTER_LEVEL_1 = 1 << 3;
+        /// House Bravery Member
+        const HYPESQUAD_ONLINE_HOUSE_1 = 1 << 6;
+        /// House Brilliance Member
+        const HYPESQUAD_ONLINE_HOUSE_2 = 1 << 7;
+        /// House Balance Member
+        const HYPESQUAD_ONLINE_HOUSE_3 = 1 << 8;
+        /// Early Nitro Supporter
+        const PREMIUM_EARLY_SUPPORTER = 1 << 9;
+        /// User is a team
+        const TEAM_PSEUDO_USER = 1 << 10;
+        /// Bug Hunter Level 2
+        const BUG_HUNTER_LEVEL_2 = 1 << 14;
+        /// Verified Bot
+        const VERIFIED_BOT = 1 << 16;
+        /// Early Verified Bot Developer
+        const VERIFIED_DEVELOPER = 1 << 17;
+        /// Moderator Programs Alumni
+        const CERTIFIED_MODERATOR = 1 << 18;
+        /// Bot uses only HTTP interactions and is shown in the online member list
+        const BOT_HTTP_INTERACTIONS = 1 << 19;
+        /// User is an Active Developer
+        const ACTIVE_DEVELOPER = 1 << 22;
+    }
+}
+
+bitflags_serde!(UserFlags);
+
+int_enum! {
+    /// Premium Types
+    ///
+    /// <https://discord.com/developers/docs/resources/user#user-object-premium-types>
+    pub enum PremiumType: u8 {
+        None = 0,
+        NitroClassic = 1,
+        Nitro = 2,
+        NitroBasic = 3,
+    }
 }
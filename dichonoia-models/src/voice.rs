@@ -0,0 +1,50 @@
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+
+use crate::{
+    guild::member::Member,
+    id::{ChannelId, GuildId, UserId},
+};
+
+/// Voice State Object
+///
+/// Used to represent a user's voice connection status.
+///
+/// <https://discord.com/developers/docs/resources/voice#voice-state-object>
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[expect(
+    clippy::struct_excessive_bools,
+    reason = "mirrors Discord's voice state object"
+)]
+pub struct VoiceState {
+    /// Guild ID this voice state is for
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub guild_id: Option<GuildId>,
+    /// Channel ID this user is connected to, or `None` if they left the voice channel
+    pub channel_id: Option<ChannelId>,
+    /// User ID this voice state is for
+    pub user_id: UserId,
+    /// Guild member this voice state is for
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub member: Option<Member>,
+    /// Session ID for this voice state
+    pub session_id: String,
+    /// Whether this user is deafened by the server
+    pub deaf: bool,
+    /// Whether this user is muted by the server
+    pub mute: bool,
+    /// Whether this user is locally deafened
+    pub self_deaf: bool,
+    /// Whether this user is locally muted
+    pub self_mute: bool,
+    /// Whether this user is streaming using "Go Live"
+    #[serde(default)]
+    pub self_stream: bool,
+    /// Whether this user's camera is enabled
+    pub self_video: bool,
+    /// Whether this user's permission to speak is denied
+    pub suppress: bool,
+    /// Time at which the user requested to speak
+    #[serde(with = "time::serde::rfc3339::option")]
+    pub request_to_speak_timestamp: Option<OffsetDateTime>,
+}
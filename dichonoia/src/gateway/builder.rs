@@ -0,0 +1,139 @@
+use crate::gateway::{Compression, Encoding, GATEWAY_URL, GatewayClient, Result};
+use dichonoia_models::gateway::events::ready::shard_info::ShardInfo;
+use dichonoia_models::gateway::presence::PresenceUpdatePayload;
+use dichonoia_models::gateway::{GatewayIntents, IdentifyPayload, IdentifyProperties};
+use std::ops::RangeInclusive;
+
+/// Values of `large_threshold` accepted by Discord.
+const LARGE_THRESHOLD: RangeInclusive<u8> = 50..=250;
+
+/// Builder for the options of a [`GatewayClient`] connection.
+///
+/// Created with [`GatewayClient::builder`].
+#[derive(Debug, Clone)]
+#[must_use]
+pub struct GatewayClientBuilder {
+    url: String,
+    identify: IdentifyPayload,
+    encoding: Encoding,
+    compression: Compression,
+}
+
+impl GatewayClientBuilder {
+    pub fn new(token: &str, intents: GatewayIntents) -> Self {
+        let identify = IdentifyPayload {
+            token: String::from(token),
+            properties: IdentifyProperties {
+                os: String::from(std::env::consts::OS),
+                device: format!("dichonoia/{}", env!("CARGO_PKG_VERSION")),
+                browser: format!("dichonoia/{}", env!("CARGO_PKG_VERSION")),
+            },
+            compress: false,
+            large_threshold: None,
+            shard: None,
+            presence: None,
+            intents,
+        };
+
+        Self {
+            url: String::from(GATEWAY_URL),
+            identify,
+            encoding: Encoding::default(),
+            compression: Compression::default(),
+        }
+    }
+
+    /// URL to connect to when identifying, such as [`GatewayBot::url`] or a gateway
+    /// proxy. Defaults to `wss://gateway.discord.gg`.
+    ///
+    /// Resuming still uses the `resume_gateway_url` received in [Ready].
+    ///
+    /// [`GatewayBot::url`]: dichonoia_models::gateway::GatewayBot::url
+    /// [Ready]: dichonoia_models::gateway::events::Ready
+    pub fn url(mut self, url: impl Into<String>) -> Self {
+        self.url = url.into();
+        self
+    }
+
+    /// Connection properties sent when identifying.
+    pub fn properties(mut self, properties: IdentifyProperties) -> Self {
+        self.identify.properties = properties;
+        self
+    }
+
+    /// Total number of members where the gateway will stop sending offline members in
+    /// the guild member list. Discord only accepts values between 50 and 250, so
+    /// `large_threshold` is clamped to that range. Defaults to 50.
+    pub const fn large_threshold(mut self, large_threshold: u8) -> Self {
+        let large_threshold = if large_threshold < *LARGE_THRESHOLD.start() {
+            *LARGE_THRESHOLD.start()
+        } else if large_threshold > *LARGE_THRESHOLD.end() {
+            *LARGE_THRESHOLD.end()
+        } else {
+            large_threshold
+        };
+
+        self.identify.large_threshold = Some(large_threshold);
+        self
+    }
+
+    /// Shard to connect as. See [`ShardManager`] to run all shards of an app at once.
+    ///
+    /// [`ShardManager`]: crate::gateway::ShardManager
+    pub const fn shard(mut self, shard: ShardInfo) -> Self {
+        self.identify.shard = Some(shard);
+        self
+    }
+
+    /// Initial presence sent when identifying.
+    pub fn presence(mut self, presence: PresenceUpdatePayload) -> Self {
+        self.identify.presence = Some(presence);
+        self
+    }
+
+    pub const fn encoding(mut self, encoding: Encoding) -> Self {
+        self.encoding = encoding;
+        self
+    }
+
+    pub const fn compression(mut self, compression: Compression) -> Self {
+        self.compression = compression;
+        self
+    }
+
+    /// # Errors
+    ///
+    /// Returns an error if the connection cannot be opened, or the identify payload
+    /// cannot be sent.
+    ///
+    /// Returns [`GatewayError::MissingHello`] if the gateway does not start the
+    /// connection with a [Hello] payload.
+    ///
+    /// [`GatewayError::MissingHello`]: crate::gateway::GatewayError::MissingHello
+    /// [Hello]: dichonoia_models::gateway::GatewayPayload::Hello
+    pub async fn connect(self) -> Result<GatewayClient> {
+        GatewayClient::connect_with(self.url, self.identify, self.encoding, self.compression).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::GatewayClientBuilder;
+    use dichonoia_models::gateway::GatewayIntents;
+
+    fn large_threshold(large_threshold: u8) -> Option<u8> {
+        GatewayClientBuilder::new("token", GatewayIntents::empty())
+            .large_threshold(large_threshold)
+            .identify
+            .large_threshold
+    }
+
+    #[test]
+    fn large_threshold_is_clamped() {
+        assert_eq!(large_threshold(0), Some(50));
+        assert_eq!(large_threshold(50), Some(50));
+        assert_eq!(large_threshold(100), Some(100));
+        assert_eq!(large_threshold(250), Some(250));
+        assert_eq!(large_threshold(u8::MAX), Some(250));
+    }
+}
@@ -0,0 +1,189 @@
+#[cfg(feature = "zlib-stream")]
+use crate::gateway::inflater::Inflater;
+use crate::gateway::{GatewayError, Result};
+use dichonoia_models::gateway::{CloseCode, GatewayPayload, HelloPayload};
+use futures_util::{SinkExt, StreamExt};
+use serde_json::Value;
+use tokio::net::TcpStream;
+use tokio_tungstenite::tungstenite::Message;
+use tokio_tungstenite::tungstenite::client::IntoClientRequest;
+use tokio_tungstenite::tungstenite::protocol::CloseFrame;
+use tokio_tungstenite::{MaybeTlsStream, WebSocketStream};
+
+type WsStream = WebSocketStream<MaybeTlsStream<TcpStream>>;
+
+/// Encoding of the payloads exchanged with the gateway, in both directions.
+///
+/// Defaults to [`Encoding::Json`]. [`Encoding::Etf`] requires the `etf` feature, and must
+/// be opted into with [`GatewayClientBuilder::encoding`].
+///
+/// [`GatewayClientBuilder::encoding`]: crate::gateway::GatewayClientBuilder::encoding
+///
+/// <https://discord.com/developers/docs/events/gateway#encoding-and-compression>
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Encoding {
+    /// Payloads are sent and received as JSON text frames.
+    #[default]
+    Json,
+    /// Payloads are sent and received as [ETF](crate::gateway::etf) binary frames.
+    #[cfg(feature = "etf")]
+    Etf,
+}
+
+impl Encoding {
+    const fn query(self) -> &'static str {
+        match self {
+            Self::Json => "json",
+            #[cfg(feature = "etf")]
+            Self::Etf => "etf",
+        }
+    }
+}
+
+/// Compression of the payloads received from the gateway.
+///
+/// Defaults to [`Compression::None`]. [`Compression::ZlibStream`] requires the
+/// `zlib-stream` feature, and must be opted into with [`GatewayClientBuilder::compression`].
+///
+/// [`GatewayClientBuilder::compression`]: crate::gateway::GatewayClientBuilder::compression
+///
+/// <https://discord.com/developers/docs/events/gateway#transport-compression>
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Compression {
+    /// Payloads are received uncompressed.
+    #[default]
+    None,
+    /// The whole receiving half of the connection is compressed with a single zlib
+    /// context, and payloads are received as binary frames.
+    #[cfg(feature = "zlib-stream")]
+    ZlibStream,
+}
+
+impl Compression {
+    const fn query(self) -> &'static str {
+        match self {
+            Self::None => "",
+            #[cfg(feature = "zlib-stream")]
+            Self::ZlibStream => "&compress=zlib-stream",
+        }
+    }
+}
+
+/// A single WebSocket connection to the gateway.
+pub struct Connection {
+    stream: WsStream,
+    encoding: Encoding,
+    #[cfg(feature = "zlib-stream")]
+    inflater: Option<Inflater>,
+}
+
+impl Connection {
+    /// Opens a new connection to `url` and waits for its [Hello] payload.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`GatewayError::MissingHello`] if the connection is closed, or another
+    /// payload is received, before [Hello].
+    ///
+    /// [Hello]: GatewayPayload::Hello
+    pub async fn open(
+        url: &str,
+        encoding: Encoding,
+        compression: Compression,
+    ) -> Result<(Self, HelloPayload)> {
+        let request = format!(
+            "{url}/?v=10&encoding={}{}",
+            encoding.query(),
+            compression.query()
+        )
+        .into_client_request()?;
+        let (stream, _response) = tokio_tungstenite::connect_async(request).await?;
+
+        let mut connection = Self {
+            stream,
+            encoding,
+            #[cfg(feature = "zlib-stream")]
+            inflater: (compression == Compression::ZlibStream).then(Inflater::new),
+        };
+
+        let Some(GatewayPayload::Hello(hello_payload)) = connection.read().await? else {
+            return Err(GatewayError::MissingHello);
+        };
+
+        Ok((connection, hello_payload))
+    }
+
+    /// Closes the connection with `code`, ignoring any error since the connection is
+    /// being discarded anyway.
+    pub async fn close(&mut self, code: u16, reason: &str) {
+        let frame = CloseFrame {
+            code: code.into(),
+            reason: reason.into(),
+        };
+        let _ = self.stream.close(Some(frame)).await;
+    }
+
+    /// Reads the next payload, or `None` if the connection was dropped or closed without
+    /// a gateway [`CloseCode`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`GatewayError::Closed`] if the connection was closed with a gateway
+    /// [`CloseCode`], regardless of whether it allows reconnecting.
+    pub async fn read(&mut self) -> Result<Option<GatewayPayload>> {
+        let payload = loop {
+            match self.stream.next().await {
+                Some(Ok(Message::Text(text))) => break self.decode(text.as_bytes())?,
+                #[cfg(feature = "zlib-stream")]
+                Some(Ok(Message::Binary(frame))) if let Some(inflater) = &mut self.inflater => {
+                    if let Some(message) = inflater.inflate(&frame)? {
+                        break self.decode(&message)?;
+                    }
+                }
+                Some(Ok(Message::Binary(message))) => break self.decode(&message)?,
+                Some(Ok(Message::Close(Some(frame)))) => {
+                    return match CloseCode::try_from(u16::from(frame.code)) {
+                        Ok(code) => Err(GatewayError::Closed {
+                            code,
+                            reason: frame.reason.to_string(),
+                        }),
+                        Err(_) => Ok(None),
+                    };
+                }
+                Some(Ok(Message::Close(None)) | Err(_)) | None => return Ok(None),
+                Some(Ok(_)) => {}
+            }
+        };
+
+        Ok(Some(payload))
+    }
+
+    pub async fn write(&mut self, payload: GatewayPayload) -> Result<()> {
+        let message = self.encode(&payload.to_json()?)?;
+        self.stream.send(message).await?;
+
+        Ok(())
+    }
+
+    /// Decodes a whole (decompressed) message.
+    fn decode(&self, message: &[u8]) -> Result<GatewayPayload> {
+        match self.encoding {
+            Encoding::Json => Ok(GatewayPayload::from_slice(message)?),
+            #[cfg(feature = "etf")]
+            Encoding::Etf => {
+                let decoder = crate::gateway::etf::Decoder::new(message)?;
+                Ok(GatewayPayload::deserialize_with(|| decoder.clone())?)
+            }
+        }
+    }
+
+    fn encode(&self, value: &Value) -> Result<Message> {
+        match self.encoding {
+            Encoding::Json => Ok(Message::Text(serde_json::to_string(value)?.into())),
+            #[cfg(feature = "etf")]
+            Encoding::Etf => Ok(Message::Binary(crate::gateway::etf::to_vec(value)?.into())),
+        }
+    }
+}
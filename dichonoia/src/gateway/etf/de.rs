@@ -0,0 +1,386 @@
+use crate::gateway::etf::{
+    ATOM_EXT, ATOM_UTF8_EXT, BINARY_EXT, EtfError, FALSE, INTEGER_EXT, LARGE_BIG_EXT,
+    LARGE_TUPLE_EXT, LIST_EXT, MAP_EXT, NEW_FLOAT_EXT, NIL, NIL_EXT, Result, SMALL_ATOM_EXT,
+    SMALL_ATOM_UTF8_EXT, SMALL_BIG_EXT, SMALL_INTEGER_EXT, SMALL_TUPLE_EXT, STRING_EXT, TRUE,
+    VERSION,
+};
+use serde::de::value::{BorrowedStrDeserializer, SeqDeserializer};
+use serde::de::{self, DeserializeSeed, EnumAccess, MapAccess, SeqAccess, VariantAccess, Visitor};
+use serde::{Deserialize, forward_to_deserialize_any};
+
+/// Deserializes `T` from a complete ETF message, including its version byte.
+///
+/// # Errors
+///
+/// Returns an error if `input` is not valid ETF, or does not match `T`.
+pub fn from_slice<'de, T: Deserialize<'de>>(input: &'de [u8]) -> Result<T> {
+    let mut decoder = Decoder::new(input)?;
+    let value = T::deserialize(&mut decoder)?;
+
+    if decoder.input.is_empty() {
+        Ok(value)
+    } else {
+        Err(EtfError::TrailingBytes)
+    }
+}
+
+/// Decoder of the terms of an ETF message.
+///
+/// It only borrows the message, so it is cheap to clone in order to decode the same
+/// message more than once, and can be used both by value and by reference.
+#[derive(Debug, Clone)]
+pub struct Decoder<'de> {
+    input: &'de [u8],
+}
+
+impl<'de> Decoder<'de> {
+    /// # Errors
+    ///
+    /// Returns an error if `input` does not start with the supported version byte.
+    pub fn new(input: &'de [u8]) -> Result<Self> {
+        let mut decoder = Self { input };
+        match decoder.read_u8()? {
+            VERSION => Ok(decoder),
+            version => Err(EtfError::UnsupportedVersion(version)),
+        }
+    }
+
+    fn peek_tag(&self) -> Result<u8> {
+        self.input.first().copied().ok_or(EtfError::Eof)
+    }
+
+    const fn read_bytes(&mut self, len: usize) -> Result<&'de [u8]> {
+        if self.input.len() < len {
+            return Err(EtfError::Eof);
+        }
+
+        let (bytes, rest) = self.input.split_at(len);
+        self.input = rest;
+        Ok(bytes)
+    }
+
+    fn read_array<const N: usize>(&mut self) -> Result<[u8; N]> {
+        let mut array = [0; N];
+        array.copy_from_slice(self.read_bytes(N)?);
+        Ok(array)
+    }
+
+    fn read_u8(&mut self) -> Result<u8> {
+        self.read_array().map(u8::from_be_bytes)
+    }
+
+    fn read_u16(&mut self) -> Result<usize> {
+        self.read_array()
+            .map(|b| usize::from(u16::from_be_bytes(b)))
+    }
+
+    fn read_u32(&mut self) -> Result<usize> {
+        let n = self.read_array().map(u32::from_be_bytes)?;
+        usize::try_from(n).map_err(|_| EtfError::TooLarge)
+    }
+
+    fn read_str(&mut self, len: usize) -> Result<&'de str> {
+        std::str::from_utf8(self.read_bytes(len)?).map_err(|_| EtfError::InvalidUtf8)
+    }
+
+    /// Reads the atom or binary at the current position as a string.
+    fn read_string(&mut self) -> Result<&'de str> {
+        match self.read_u8()? {
+            ATOM_EXT | ATOM_UTF8_EXT => {
+                let len = self.read_u16()?;
+                self.read_str(len)
+            }
+            SMALL_ATOM_EXT | SMALL_ATOM_UTF8_EXT => {
+                let len = usize::from(self.read_u8()?);
+                self.read_str(len)
+            }
+            BINARY_EXT => {
+                let len = self.read_u32()?;
+                self.read_str(len)
+            }
+            tag => Err(EtfError::UnsupportedTag(tag)),
+        }
+    }
+
+    fn read_big<V: Visitor<'de>>(&mut self, len: usize, visitor: V) -> Result<V::Value> {
+        let negative = self.read_u8()? != 0;
+        let digits = self.read_bytes(len)?;
+
+        if digits.len() > 8 && digits[8..].iter().any(|&digit| digit != 0) {
+            return Err(EtfError::IntegerOverflow);
+        }
+        let magnitude = digits
+            .iter()
+            .take(8)
+            .rev()
+            .fold(0, |n, &digit| (n << 8) | u64::from(digit));
+
+        if negative {
+            let n = 0i64
+                .checked_sub_unsigned(magnitude)
+                .ok_or(EtfError::IntegerOverflow)?;
+            visitor.visit_i64(n)
+        } else {
+            visitor.visit_u64(magnitude)
+        }
+    }
+
+    fn visit_list<V: Visitor<'de>>(&mut self, len: usize, visitor: V) -> Result<V::Value> {
+        let mut access = Elements {
+            decoder: self,
+            remaining: len,
+        };
+        let value = visitor.visit_seq(&mut access)?;
+
+        if access.remaining != 0 {
+            return Err(de::Error::invalid_length(len, &"fewer elements in list"));
+        }
+        Ok(value)
+    }
+}
+
+impl<'de> de::Deserializer<'de> for &mut Decoder<'de> {
+    type Error = EtfError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        match self.peek_tag()? {
+            ATOM_EXT | ATOM_UTF8_EXT | SMALL_ATOM_EXT | SMALL_ATOM_UTF8_EXT => {
+                match self.read_string()? {
+                    NIL => visitor.visit_unit(),
+                    TRUE => visitor.visit_bool(true),
+                    FALSE => visitor.visit_bool(false),
+                    atom => visitor.visit_borrowed_str(atom),
+                }
+            }
+            BINARY_EXT => {
+                self.read_u8()?;
+                let len = self.read_u32()?;
+                let bytes = self.read_bytes(len)?;
+
+                match std::str::from_utf8(bytes) {
+                    Ok(str) => visitor.visit_borrowed_str(str),
+                    Err(_) => visitor.visit_borrowed_bytes(bytes),
+                }
+            }
+            tag => {
+                self.read_u8()?;
+                match tag {
+                    SMALL_INTEGER_EXT => visitor.visit_u8(self.read_u8()?),
+                    INTEGER_EXT => visitor.visit_i32(self.read_array().map(i32::from_be_bytes)?),
+                    NEW_FLOAT_EXT => visitor.visit_f64(self.read_array().map(f64::from_be_bytes)?),
+                    SMALL_BIG_EXT => {
+                        let len = usize::from(self.read_u8()?);
+                        self.read_big(len, visitor)
+                    }
+                    LARGE_BIG_EXT => {
+                        let len = self.read_u32()?;
+                        self.read_big(len, visitor)
+                    }
+                    NIL_EXT => visitor.visit_seq(SeqDeserializer::new(std::iter::empty::<u8>())),
+                    STRING_EXT => {
+                        let len = self.read_u16()?;
+                        let bytes = self.read_bytes(len)?;
+                        visitor.visit_seq(SeqDeserializer::new(bytes.iter().copied()))
+                    }
+                    LIST_EXT => {
+                        let len = self.read_u32()?;
+                        let value = self.visit_list(len, visitor)?;
+
+                        match self.read_u8()? {
+                            NIL_EXT => Ok(value),
+                            _ => Err(EtfError::ImproperList),
+                        }
+                    }
+                    SMALL_TUPLE_EXT => {
+                        let len = usize::from(self.read_u8()?);
+                        self.visit_list(len, visitor)
+                    }
+                    LARGE_TUPLE_EXT => {
+                        let len = self.read_u32()?;
+                        self.visit_list(len, visitor)
+                    }
+                    MAP_EXT => {
+                        let len = self.read_u32()?;
+                        let mut access = Elements {
+                            decoder: self,
+                            remaining: len,
+                        };
+                        let value = visitor.visit_map(&mut access)?;
+
+                        if access.remaining != 0 {
+                            return Err(de::Error::invalid_length(len, &"fewer entries in map"));
+                        }
+                        Ok(value)
+                    }
+                    tag => Err(EtfError::UnsupportedTag(tag)),
+                }
+            }
+        }
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        let is_atom = matches!(
+            self.peek_tag()?,
+            ATOM_EXT | ATOM_UTF8_EXT | SMALL_ATOM_EXT | SMALL_ATOM_UTF8_EXT
+        );
+
+        if is_atom {
+            let mut lookahead = Decoder { input: self.input };
+            if lookahead.read_string()? == NIL {
+                self.input = lookahead.input;
+                return visitor.visit_none();
+            }
+        }
+
+        visitor.visit_some(self)
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value> {
+        if self.peek_tag()? == MAP_EXT {
+            self.read_u8()?;
+            if self.read_u32()? != 1 {
+                return Err(de::Error::custom("expected a map with a single variant"));
+            }
+
+            visitor.visit_enum(Variant { decoder: self })
+        } else {
+            let variant = BorrowedStrDeserializer::new(self.read_string()?);
+            visitor.visit_enum(variant)
+        }
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string bytes byte_buf
+        unit unit_struct seq tuple tuple_struct map struct identifier ignored_any
+    }
+}
+
+impl<'de> de::Deserializer<'de> for Decoder<'de> {
+    type Error = EtfError;
+
+    fn deserialize_any<V: Visitor<'de>>(mut self, visitor: V) -> Result<V::Value> {
+        (&mut self).deserialize_any(visitor)
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(mut self, visitor: V) -> Result<V::Value> {
+        (&mut self).deserialize_option(visitor)
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        mut self,
+        name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value> {
+        (&mut self).deserialize_newtype_struct(name, visitor)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        mut self,
+        name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value> {
+        (&mut self).deserialize_enum(name, variants, visitor)
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string bytes byte_buf
+        unit unit_struct seq tuple tuple_struct map struct identifier ignored_any
+    }
+}
+
+/// Elements of a list, tuple or map.
+struct Elements<'a, 'de> {
+    decoder: &'a mut Decoder<'de>,
+    remaining: usize,
+}
+
+impl<'de> SeqAccess<'de> for Elements<'_, 'de> {
+    type Error = EtfError;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(&mut self, seed: T) -> Result<Option<T::Value>> {
+        if self.remaining == 0 {
+            return Ok(None);
+        }
+
+        self.remaining -= 1;
+        seed.deserialize(&mut *self.decoder).map(Some)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.remaining)
+    }
+}
+
+impl<'de> MapAccess<'de> for Elements<'_, 'de> {
+    type Error = EtfError;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(&mut self, seed: K) -> Result<Option<K::Value>> {
+        if self.remaining == 0 {
+            return Ok(None);
+        }
+
+        self.remaining -= 1;
+        seed.deserialize(&mut *self.decoder).map(Some)
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(&mut self, seed: V) -> Result<V::Value> {
+        seed.deserialize(&mut *self.decoder)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.remaining)
+    }
+}
+
+/// Enum variant with content, encoded as a single entry map of `variant => content`.
+struct Variant<'a, 'de> {
+    decoder: &'a mut Decoder<'de>,
+}
+
+impl<'de> EnumAccess<'de> for Variant<'_, 'de> {
+    type Error = EtfError;
+    type Variant = Self;
+
+    fn variant_seed<V: DeserializeSeed<'de>>(self, seed: V) -> Result<(V::Value, Self)> {
+        let variant = seed.deserialize(&mut *self.decoder)?;
+        Ok((variant, self))
+    }
+}
+
+impl<'de> VariantAccess<'de> for Variant<'_, 'de> {
+    type Error = EtfError;
+
+    fn unit_variant(self) -> Result<()> {
+        <()>::deserialize(self.decoder)
+    }
+
+    fn newtype_variant_seed<T: DeserializeSeed<'de>>(self, seed: T) -> Result<T::Value> {
+        seed.deserialize(self.decoder)
+    }
+
+    fn tuple_variant<V: Visitor<'de>>(self, _len: usize, visitor: V) -> Result<V::Value> {
+        de::Deserializer::deserialize_seq(self.decoder, visitor)
+    }
+
+    fn struct_variant<V: Visitor<'de>>(
+        self,
+        _fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value> {
+        de::Deserializer::deserialize_map(self.decoder, visitor)
+    }
+}
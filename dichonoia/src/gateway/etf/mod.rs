@@ -0,0 +1,327 @@
+//! Serde implementation of the [External Term Format], used by the gateway when
+//! connecting with `encoding=etf`.
+//!
+//! Terms map to the serde data model the same way the gateway's JSON does:
+//! * binaries and atoms are strings, except for the atoms `nil` (null), `true` and
+//!   `false`,
+//! * lists and tuples are sequences, maps are maps,
+//! * integers and big integers are 64-bit integers.
+//!
+//! <div class="note">
+//!
+//! Over ETF, snowflakes are sent as integers instead of strings.
+//! </div>
+//!
+//! <https://discord.com/developers/docs/topics/gateway#encoding-and-compression>
+//!
+//! [External Term Format]: https://www.erlang.org/doc/apps/erts/erl_ext_dist.html
+
+mod de;
+mod ser;
+
+pub use de::{Decoder, from_slice};
+pub use ser::{Encoder, to_vec};
+
+use std::fmt::Display;
+
+pub type Result<T, E = EtfError> = std::result::Result<T, E>;
+
+const VERSION: u8 = 131;
+
+const NEW_FLOAT_EXT: u8 = 70;
+const SMALL_INTEGER_EXT: u8 = 97;
+const INTEGER_EXT: u8 = 98;
+const ATOM_EXT: u8 = 100;
+const SMALL_TUPLE_EXT: u8 = 104;
+const LARGE_TUPLE_EXT: u8 = 105;
+const NIL_EXT: u8 = 106;
+const STRING_EXT: u8 = 107;
+const LIST_EXT: u8 = 108;
+const BINARY_EXT: u8 = 109;
+const SMALL_BIG_EXT: u8 = 110;
+const LARGE_BIG_EXT: u8 = 111;
+const SMALL_ATOM_EXT: u8 = 115;
+const MAP_EXT: u8 = 116;
+const ATOM_UTF8_EXT: u8 = 118;
+const SMALL_ATOM_UTF8_EXT: u8 = 119;
+
+const NIL: &str = "nil";
+const TRUE: &str = "true";
+const FALSE: &str = "false";
+
+#[derive(Debug, thiserror::Error)]
+pub enum EtfError {
+    #[error("{0}")]
+    Custom(String),
+    #[error("Unexpected end of input")]
+    Eof,
+    #[error("Trailing bytes after the term")]
+    TrailingBytes,
+    #[error("Unsupported format version {0}")]
+    UnsupportedVersion(u8),
+    #[error("Unsupported term tag {0}")]
+    UnsupportedTag(u8),
+    #[error("Integer does not fit in 64 bits")]
+    IntegerOverflow,
+    #[error("Invalid UTF-8 in string")]
+    InvalidUtf8,
+    #[error("Improper lists are not supported")]
+    ImproperList,
+    #[error("Term is too large to be encoded")]
+    TooLarge,
+}
+
+impl serde::de::Error for EtfError {
+    fn custom<T: Display>(msg: T) -> Self {
+        Self::Custom(msg.to_string())
+    }
+}
+
+impl serde::ser::Error for EtfError {
+    fn custom<T: Display>(msg: T) -> Self {
+        Self::Custom(msg.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{EtfError, from_slice, to_vec};
+    use crate::gateway::etf::Decoder;
+    use dichonoia_models::gateway::events::GuildRoleDelete;
+    use dichonoia_models::gateway::{GatewayEvent, GatewayPayload, PayloadError};
+    use dichonoia_models::id::UserId;
+    use dichonoia_models::message::component::Component;
+    use serde::{Deserialize, Serialize};
+    use serde_json::{Value, json};
+    use std::collections::HashMap;
+
+    /// Binary `"a"`.
+    const KEY_A: [u8; 6] = [109, 0, 0, 0, 1, b'a'];
+    /// Binary `"b"`.
+    const KEY_B: [u8; 6] = [109, 0, 0, 0, 1, b'b'];
+
+    fn decode_payload(input: &[u8]) -> Result<GatewayPayload, PayloadError<EtfError>> {
+        let decoder = Decoder::new(input).map_err(PayloadError::Invalid)?;
+        GatewayPayload::deserialize_with(|| decoder.clone())
+    }
+
+    #[test]
+    fn snowflake_from_big_integer() {
+        // SMALL_BIG_EXT of 175928847299117063, with its digits in little endian.
+        let input = [131, 110, 8, 0, 7, 0, 2, 193, 90, 6, 113, 2];
+
+        let id: UserId = from_slice(&input).expect("valid snowflake");
+        assert_eq!(id.get(), 175_928_847_299_117_063);
+    }
+
+    #[test]
+    fn big_integers() {
+        let negative = [131, 110, 2, 1, 0x39, 0x30];
+        assert_eq!(from_slice::<i64>(&negative).expect("valid integer"), -12345);
+
+        let overflowing = [131, 110, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1];
+        assert!(matches!(
+            from_slice::<u64>(&overflowing),
+            Err(EtfError::IntegerOverflow)
+        ));
+    }
+
+    #[test]
+    fn atoms() {
+        let nil = [131, 115, 3, b'n', b'i', b'l'];
+        assert_eq!(from_slice::<Option<u8>>(&nil).expect("valid atom"), None);
+        assert_eq!(from_slice::<Value>(&nil).expect("valid atom"), Value::Null);
+
+        let small_true = [131, 119, 4, b't', b'r', b'u', b'e'];
+        assert!(from_slice::<bool>(&small_true).expect("valid atom"));
+
+        let large_false = [131, 100, 0, 5, b'f', b'a', b'l', b's', b'e'];
+        assert!(!from_slice::<bool>(&large_false).expect("valid atom"));
+
+        let other = [131, 119, 2, b'o', b'k'];
+        assert_eq!(from_slice::<String>(&other).expect("valid atom"), "ok");
+    }
+
+    #[test]
+    fn empty_list() {
+        let input = [131, 106];
+
+        assert!(
+            from_slice::<Vec<u8>>(&input)
+                .expect("valid list")
+                .is_empty()
+        );
+        assert_eq!(to_vec(&Vec::<u8>::new()).expect("encodable"), input);
+    }
+
+    #[test]
+    fn lists() {
+        let input = [131, 108, 0, 0, 0, 2, 97, 1, 97, 2, 106];
+        assert_eq!(from_slice::<Vec<u8>>(&input).expect("valid list"), [1, 2]);
+
+        let improper = [131, 108, 0, 0, 0, 1, 97, 1, 97, 2];
+        assert!(matches!(
+            from_slice::<Vec<u8>>(&improper),
+            Err(EtfError::ImproperList)
+        ));
+    }
+
+    #[test]
+    fn maps() {
+        #[derive(Debug, PartialEq, Deserialize)]
+        struct Entry {
+            a: u8,
+            b: bool,
+        }
+
+        let mut input = vec![131, 116, 0, 0, 0, 2];
+        input.extend(KEY_A);
+        input.extend([97, 1]);
+        input.extend(KEY_B);
+        input.extend([119, 4, b't', b'r', b'u', b'e']);
+
+        let entry: Entry = from_slice(&input).expect("valid map");
+        assert_eq!(entry, Entry { a: 1, b: true });
+
+        let map: HashMap<String, Value> = from_slice(&input).expect("valid map");
+        assert_eq!(map["a"], json!(1));
+        assert_eq!(map["b"], json!(true));
+    }
+
+    #[test]
+    fn trailing_bytes() {
+        assert!(matches!(
+            from_slice::<u8>(&[131, 97, 1, 97]),
+            Err(EtfError::TrailingBytes)
+        ));
+    }
+
+    #[test]
+    fn round_trip() {
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct Term {
+            id: UserId,
+            name: String,
+            small: u8,
+            negative: i32,
+            big: u64,
+            float: f64,
+            flag: bool,
+            missing: Option<u8>,
+            empty: Vec<u8>,
+            nested: Vec<Vec<i64>>,
+        }
+
+        let term = Term {
+            id: "175928847299117063".parse().expect("valid snowflake"),
+            name: String::from("dichonoia"),
+            small: 255,
+            negative: -300,
+            big: u64::MAX,
+            float: 1.5,
+            flag: true,
+            missing: None,
+            empty: Vec::new(),
+            nested: vec![vec![i64::MIN, 0], Vec::new()],
+        };
+
+        let encoded = to_vec(&term).expect("encodable");
+        let decoded: Term = from_slice(&encoded).expect("decodable");
+        assert_eq!(decoded, term);
+    }
+
+    #[test]
+    fn payload() {
+        let hello = json!({ "op": 10, "d": { "heartbeat_interval": 41250 }, "s": null });
+        let payload = decode_payload(&to_vec(&hello).expect("encodable"));
+
+        assert!(matches!(
+            payload,
+            Ok(GatewayPayload::Hello(hello)) if hello.heartbeat_interval == 41250
+        ));
+    }
+
+    #[test]
+    fn dispatch_with_data_before_name() {
+        #[derive(Serialize)]
+        struct Dispatch {
+            d: Value,
+            op: u8,
+            s: i64,
+            t: &'static str,
+        }
+
+        let dispatch = Dispatch {
+            d: json!({ "guild_id": 1_u64 << 40, "role_id": 2 }),
+            op: 0,
+            s: 3,
+            t: "GUILD_ROLE_DELETE",
+        };
+        let payload = decode_payload(&to_vec(&dispatch).expect("encodable"));
+
+        let Ok(GatewayPayload::Dispatch(dispatch)) = payload else {
+            panic!("expected a dispatch, got {payload:?}");
+        };
+        let Some(GatewayEvent::GuildRoleDelete(GuildRoleDelete { guild_id, role_id })) =
+            dispatch.data
+        else {
+            panic!("expected a role delete, got {:?}", dispatch.data);
+        };
+        assert_eq!(
+            (guild_id.get(), role_id.get(), dispatch.sequence),
+            (1 << 40, 2, 3)
+        );
+    }
+
+    #[test]
+    fn dispatch_with_invalid_event() {
+        let dispatch = json!({
+            "op": 0,
+            "s": 4,
+            "t": "GUILD_ROLE_DELETE",
+            "d": { "guild_id": 1, "role_id": 0 },
+        });
+        let payload = decode_payload(&to_vec(&dispatch).expect("encodable"));
+
+        let Err(PayloadError::Event(err)) = payload else {
+            panic!("expected an event error, got {payload:?}");
+        };
+        assert_eq!(err.name, "GUILD_ROLE_DELETE");
+        assert_eq!(err.sequence, 4);
+        assert_eq!(err.data, json!({ "guild_id": 1, "role_id": 0 }));
+    }
+
+    #[test]
+    fn unknown_dispatch() {
+        let dispatch = json!({ "op": 0, "s": 5, "t": "NOT_AN_EVENT", "d": { "a": [1, null] } });
+        let payload = decode_payload(&to_vec(&dispatch).expect("encodable"));
+
+        let Ok(GatewayPayload::Dispatch(dispatch)) = payload else {
+            panic!("expected a dispatch, got {payload:?}");
+        };
+        let Some(GatewayEvent::Unknown { name, data }) = dispatch.data else {
+            panic!("expected an unknown event, got {:?}", dispatch.data);
+        };
+        assert_eq!(name, "NOT_AN_EVENT");
+        assert_eq!(data, json!({ "a": [1, null] }));
+    }
+
+    #[test]
+    fn component_with_binary_keys() {
+        #[derive(Serialize)]
+        struct TextDisplay {
+            content: &'static str,
+            #[serde(rename = "type")]
+            kind: u8,
+        }
+
+        let text = TextDisplay {
+            content: "hi",
+            kind: 10,
+        };
+        let component: Component =
+            from_slice(&to_vec(&text).expect("encodable")).expect("decodable");
+
+        assert!(matches!(component, Component::TextDisplay(text) if text.content == "hi"));
+    }
+}
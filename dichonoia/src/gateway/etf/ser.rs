@@ -0,0 +1,398 @@
+use crate::gateway::etf::{
+    BINARY_EXT, EtfError, FALSE, INTEGER_EXT, LIST_EXT, MAP_EXT, NEW_FLOAT_EXT, NIL, NIL_EXT,
+    Result, SMALL_ATOM_UTF8_EXT, SMALL_BIG_EXT, SMALL_INTEGER_EXT, TRUE, VERSION,
+};
+use serde::Serialize;
+use serde::ser::{
+    self, SerializeMap, SerializeSeq, SerializeStruct, SerializeStructVariant, SerializeTuple,
+    SerializeTupleStruct, SerializeTupleVariant,
+};
+
+/// Serializes `value` into a complete ETF message, including its version byte.
+///
+/// # Errors
+///
+/// Returns an error if `value` cannot be represented in ETF.
+pub fn to_vec<T: Serialize + ?Sized>(value: &T) -> Result<Vec<u8>> {
+    let mut encoder = Encoder {
+        output: vec![VERSION],
+    };
+    value.serialize(&mut encoder)?;
+
+    Ok(encoder.output)
+}
+
+pub struct Encoder {
+    output: Vec<u8>,
+}
+
+impl Encoder {
+    fn write_atom(&mut self, atom: &str) {
+        self.output.push(SMALL_ATOM_UTF8_EXT);
+        // Only used for `nil`, `true` and `false`, which are all shorter than 255 bytes.
+        #[expect(clippy::cast_possible_truncation)]
+        self.output.push(atom.len() as u8);
+        self.output.extend_from_slice(atom.as_bytes());
+    }
+
+    fn write_binary(&mut self, bytes: &[u8]) -> Result<()> {
+        let len = u32::try_from(bytes.len()).map_err(|_| EtfError::TooLarge)?;
+
+        self.output.push(BINARY_EXT);
+        self.output.extend_from_slice(&len.to_be_bytes());
+        self.output.extend_from_slice(bytes);
+        Ok(())
+    }
+
+    fn write_integer(&mut self, negative: bool, magnitude: u64) {
+        let n = if negative {
+            -i128::from(magnitude)
+        } else {
+            i128::from(magnitude)
+        };
+
+        if let Ok(n) = u8::try_from(n) {
+            self.output.push(SMALL_INTEGER_EXT);
+            self.output.push(n);
+        } else if let Ok(n) = i32::try_from(n) {
+            self.output.push(INTEGER_EXT);
+            self.output.extend_from_slice(&n.to_be_bytes());
+        } else {
+            let digits = magnitude.to_le_bytes();
+            let len = digits
+                .iter()
+                .rposition(|&digit| digit != 0)
+                .map_or(0, |i| i + 1);
+
+            self.output.push(SMALL_BIG_EXT);
+            #[expect(clippy::cast_possible_truncation)]
+            self.output.push(len as u8);
+            self.output.push(u8::from(negative));
+            self.output.extend_from_slice(&digits[..len]);
+        }
+    }
+
+    /// Starts a list or map whose length is written once it ends.
+    fn begin(&mut self, tag: u8) -> Compound<'_> {
+        let start = self.output.len();
+        self.output.push(tag);
+        self.output.extend_from_slice(&[0; 4]);
+
+        Compound {
+            encoder: self,
+            start,
+            len: 0,
+        }
+    }
+
+    /// Wraps the content of an enum variant into a single entry map of
+    /// `variant => content`.
+    fn begin_variant(&mut self, variant: &str) -> Result<()> {
+        self.output.push(MAP_EXT);
+        self.output.extend_from_slice(&1u32.to_be_bytes());
+        self.write_binary(variant.as_bytes())
+    }
+}
+
+/// List or map being serialized.
+pub struct Compound<'a> {
+    encoder: &'a mut Encoder,
+    start: usize,
+    len: u32,
+}
+
+impl Compound<'_> {
+    fn element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
+        self.len = self.len.checked_add(1).ok_or(EtfError::TooLarge)?;
+        value.serialize(&mut *self.encoder)
+    }
+
+    fn end_list(mut self) {
+        if self.len == 0 {
+            // An empty list is encoded as `NIL_EXT` only.
+            self.encoder.output.truncate(self.start);
+        } else {
+            self.write_len();
+        }
+
+        self.encoder.output.push(NIL_EXT);
+    }
+
+    fn write_len(&mut self) {
+        let range = self.start + 1..self.start + 5;
+        self.encoder.output[range].copy_from_slice(&self.len.to_be_bytes());
+    }
+}
+
+impl<'a> ser::Serializer for &'a mut Encoder {
+    type Ok = ();
+    type Error = EtfError;
+
+    type SerializeSeq = Compound<'a>;
+    type SerializeTuple = Compound<'a>;
+    type SerializeTupleStruct = Compound<'a>;
+    type SerializeTupleVariant = Compound<'a>;
+    type SerializeMap = Compound<'a>;
+    type SerializeStruct = Compound<'a>;
+    type SerializeStructVariant = Compound<'a>;
+
+    fn serialize_bool(self, v: bool) -> Result<()> {
+        self.write_atom(if v { TRUE } else { FALSE });
+        Ok(())
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<()> {
+        self.serialize_i64(i64::from(v))
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<()> {
+        self.serialize_i64(i64::from(v))
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<()> {
+        self.serialize_i64(i64::from(v))
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<()> {
+        self.write_integer(v.is_negative(), v.unsigned_abs());
+        Ok(())
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<()> {
+        self.serialize_u64(u64::from(v))
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<()> {
+        self.serialize_u64(u64::from(v))
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<()> {
+        self.serialize_u64(u64::from(v))
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<()> {
+        self.write_integer(false, v);
+        Ok(())
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<()> {
+        self.serialize_f64(f64::from(v))
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<()> {
+        self.output.push(NEW_FLOAT_EXT);
+        self.output.extend_from_slice(&v.to_be_bytes());
+        Ok(())
+    }
+
+    fn serialize_char(self, v: char) -> Result<()> {
+        self.serialize_str(v.encode_utf8(&mut [0; 4]))
+    }
+
+    fn serialize_str(self, v: &str) -> Result<()> {
+        self.write_binary(v.as_bytes())
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<()> {
+        self.write_binary(v)
+    }
+
+    fn serialize_none(self) -> Result<()> {
+        self.serialize_unit()
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<()> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<()> {
+        self.write_atom(NIL);
+        Ok(())
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<()> {
+        self.serialize_unit()
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+    ) -> Result<()> {
+        self.serialize_str(variant)
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<()> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<()> {
+        self.begin_variant(variant)?;
+        value.serialize(self)
+    }
+
+    fn serialize_seq(self, _len: Option<usize>) -> Result<Compound<'a>> {
+        Ok(self.begin(LIST_EXT))
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<Compound<'a>> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_struct(self, _name: &'static str, len: usize) -> Result<Compound<'a>> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<Compound<'a>> {
+        self.begin_variant(variant)?;
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<Compound<'a>> {
+        Ok(self.begin(MAP_EXT))
+    }
+
+    fn serialize_struct(self, _name: &'static str, len: usize) -> Result<Compound<'a>> {
+        self.serialize_map(Some(len))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<Compound<'a>> {
+        self.begin_variant(variant)?;
+        self.serialize_map(Some(len))
+    }
+}
+
+impl SerializeSeq for Compound<'_> {
+    type Ok = ();
+    type Error = EtfError;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
+        self.element(value)
+    }
+
+    fn end(self) -> Result<()> {
+        self.end_list();
+        Ok(())
+    }
+}
+
+impl SerializeTuple for Compound<'_> {
+    type Ok = ();
+    type Error = EtfError;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
+        self.element(value)
+    }
+
+    fn end(self) -> Result<()> {
+        self.end_list();
+        Ok(())
+    }
+}
+
+impl SerializeTupleStruct for Compound<'_> {
+    type Ok = ();
+    type Error = EtfError;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
+        self.element(value)
+    }
+
+    fn end(self) -> Result<()> {
+        self.end_list();
+        Ok(())
+    }
+}
+
+impl SerializeTupleVariant for Compound<'_> {
+    type Ok = ();
+    type Error = EtfError;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
+        self.element(value)
+    }
+
+    fn end(self) -> Result<()> {
+        self.end_list();
+        Ok(())
+    }
+}
+
+impl SerializeMap for Compound<'_> {
+    type Ok = ();
+    type Error = EtfError;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<()> {
+        self.element(key)
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
+        value.serialize(&mut *self.encoder)
+    }
+
+    fn end(mut self) -> Result<()> {
+        self.write_len();
+        Ok(())
+    }
+}
+
+impl SerializeStruct for Compound<'_> {
+    type Ok = ();
+    type Error = EtfError;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<()> {
+        self.element(key)?;
+        value.serialize(&mut *self.encoder)
+    }
+
+    fn end(mut self) -> Result<()> {
+        self.write_len();
+        Ok(())
+    }
+}
+
+impl SerializeStructVariant for Compound<'_> {
+    type Ok = ();
+    type Error = EtfError;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<()> {
+        self.element(key)?;
+        value.serialize(&mut *self.encoder)
+    }
+
+    fn end(mut self) -> Result<()> {
+        self.write_len();
+        Ok(())
+    }
+}
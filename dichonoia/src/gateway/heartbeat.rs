@@ -0,0 +1,59 @@
+use std::pin::Pin;
+use std::time::Duration;
+use tokio::time::{Instant, Sleep};
+
+/// Heartbeat bookkeeping of a single gateway connection.
+///
+/// The first heartbeat is sent after `heartbeat_interval * jitter`, where `jitter` is a
+/// random value between 0 and 1, and every `heartbeat_interval` after that. If no
+/// [Heartbeat ACK] was received between two heartbeats, the connection is considered
+/// *zombied*.
+///
+/// <https://discord.com/developers/docs/events/gateway#sending-heartbeats>
+///
+/// [Heartbeat ACK]: dichonoia_models::gateway::GatewayPayload::HeartBeatACK
+pub struct Heartbeater {
+    interval: Duration,
+    next_beat: Pin<Box<Sleep>>,
+    awaiting_ack: bool,
+}
+
+impl Heartbeater {
+    pub fn new(interval: Duration) -> Self {
+        let jitter = interval.mul_f64(rand::random());
+
+        Self {
+            interval,
+            next_beat: Box::pin(tokio::time::sleep(jitter)),
+            awaiting_ack: false,
+        }
+    }
+
+    pub const fn interval(&self) -> Duration {
+        self.interval
+    }
+
+    /// Waits until the next heartbeat is due.
+    ///
+    /// This is cancel safe: the next deadline is only scheduled once the current one
+    /// has elapsed.
+    pub async fn tick(&mut self) {
+        self.next_beat.as_mut().await;
+
+        let next = Instant::now() + self.interval;
+        self.next_beat.as_mut().reset(next);
+    }
+
+    /// Whether the previously sent heartbeat has not been acknowledged yet.
+    pub const fn is_zombied(&self) -> bool {
+        self.awaiting_ack
+    }
+
+    pub const fn sent(&mut self) {
+        self.awaiting_ack = true;
+    }
+
+    pub const fn acknowledged(&mut self) {
+        self.awaiting_ack = false;
+    }
+}
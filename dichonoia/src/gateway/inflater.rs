@@ -0,0 +1,127 @@
+use flate2::{Decompress, DecompressError, FlushDecompress};
+
+/// Every complete message ends with this suffix, which is emitted by a zlib `Z_SYNC_FLUSH`.
+const ZLIB_SUFFIX: [u8; 4] = [0x00, 0x00, 0xff, 0xff];
+
+/// Decompression context of a `zlib-stream` compressed connection.
+///
+/// All messages of a connection are compressed with the same zlib context, so it must be
+/// kept around for the whole connection, and recreated for each new one. A message may
+/// also span multiple binary frames, only the last of which ends with [`ZLIB_SUFFIX`].
+///
+/// <https://discord.com/developers/docs/events/gateway#transport-compression>
+pub struct Inflater {
+    decompress: Decompress,
+    buffer: Vec<u8>,
+}
+
+impl Inflater {
+    pub fn new() -> Self {
+        Self {
+            decompress: Decompress::new(true),
+            buffer: Vec::new(),
+        }
+    }
+
+    /// Buffers `frame`, and decompresses the buffered message once it is complete.
+    pub fn inflate(&mut self, frame: &[u8]) -> Result<Option<Vec<u8>>, DecompressError> {
+        self.buffer.extend_from_slice(frame);
+
+        if !self.buffer.ends_with(&ZLIB_SUFFIX) {
+            return Ok(None);
+        }
+
+        let mut message = Vec::with_capacity(self.buffer.len() * 4);
+        let mut offset = 0;
+        loop {
+            if message.len() == message.capacity() {
+                message.reserve(message.capacity());
+            }
+
+            let (total_in, total_out) = (self.decompress.total_in(), self.decompress.total_out());
+            self.decompress.decompress_vec(
+                &self.buffer[offset..],
+                &mut message,
+                FlushDecompress::Sync,
+            )?;
+
+            #[expect(clippy::cast_possible_truncation)]
+            let consumed = (self.decompress.total_in() - total_in) as usize;
+            offset += consumed;
+
+            // The decompressor may hold back output even with input left, so stop only
+            // once it makes no more progress.
+            if consumed == 0 && self.decompress.total_out() == total_out {
+                break;
+            }
+        }
+
+        self.buffer.clear();
+        Ok(Some(message))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Inflater;
+
+    /// First two messages of a connection, as recorded from a `zlib-stream` connection.
+    const HELLO: &[u8] = br#"{"op":10,"d":{"heartbeat_interval":41250}}"#;
+    const HELLO_FRAME: [u8; 50] = [
+        0x78, 0x9c, 0xaa, 0x56, 0xca, 0x2f, 0x50, 0xb2, 0x32, 0x34, 0xd0, 0x51, 0x4a, 0x51, 0xb2,
+        0xaa, 0x56, 0xca, 0x48, 0x4d, 0x2c, 0x2a, 0x49, 0x4a, 0x4d, 0x2c, 0x89, 0xcf, 0xcc, 0x2b,
+        0x49, 0x2d, 0x2a, 0x4b, 0xcc, 0x51, 0xb2, 0x32, 0x31, 0x34, 0x32, 0x35, 0xa8, 0xad, 0x05,
+        0x00, 0x00, 0x00, 0xff, 0xff,
+    ];
+    const ACK: &[u8] = br#"{"op":11,"d":null}"#;
+    const ACK_FRAME: [u8; 17] = [
+        0xaa, 0x86, 0xa8, 0x34, 0x04, 0xab, 0xcc, 0x2b, 0xcd, 0xc9, 0xa9, 0x05, 0x00, 0x00, 0x00,
+        0xff, 0xff,
+    ];
+
+    #[test]
+    fn single_frame() {
+        let mut inflater = Inflater::new();
+
+        let message = inflater.inflate(&HELLO_FRAME).expect("valid frame");
+        assert_eq!(message.as_deref(), Some(HELLO));
+    }
+
+    #[test]
+    fn message_split_across_frames() {
+        let mut inflater = Inflater::new();
+        let (first, second) = HELLO_FRAME.split_at(20);
+
+        assert_eq!(inflater.inflate(first).expect("valid frame"), None);
+        let message = inflater.inflate(second).expect("valid frame");
+        assert_eq!(message.as_deref(), Some(HELLO));
+    }
+
+    #[test]
+    fn waits_for_suffix() {
+        let mut inflater = Inflater::new();
+        let (body, suffix) = HELLO_FRAME.split_at(HELLO_FRAME.len() - 4);
+        let (partial_suffix, last_byte) = suffix.split_at(3);
+
+        // The body is decompressible on its own, but the message is only complete once
+        // the whole suffix has been received.
+        assert_eq!(inflater.inflate(body).expect("valid frame"), None);
+        assert_eq!(inflater.inflate(partial_suffix).expect("valid frame"), None);
+        let message = inflater.inflate(last_byte).expect("valid frame");
+        assert_eq!(message.as_deref(), Some(HELLO));
+    }
+
+    #[test]
+    fn context_is_shared_across_messages() {
+        let mut inflater = Inflater::new();
+
+        let hello = inflater.inflate(&HELLO_FRAME).expect("valid frame");
+        assert_eq!(hello.as_deref(), Some(HELLO));
+        let ack = inflater.inflate(&ACK_FRAME).expect("valid frame");
+        assert_eq!(ack.as_deref(), Some(ACK));
+
+        // The second message refers back to the first one, so it cannot be decompressed
+        // by a new context.
+        assert!(Inflater::new().inflate(&ACK_FRAME).is_err());
+    }
+}
@@ -0,0 +1,124 @@
+use dichonoia_models::gateway::EventError;
+use dichonoia_models::gateway::events::GuildMembersChunk;
+use dichonoia_models::gateway::presence::Presence;
+use dichonoia_models::guild::member::Member;
+use dichonoia_models::id::{GuildId, UserId};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, PoisonError};
+use tokio::sync::oneshot;
+
+/// Every member matching a [Request Guild Members], reassembled from all of its
+/// [Guild Members Chunk] events.
+///
+/// [Request Guild Members]: dichonoia_models::gateway::request_guild_members::RequestGuildMembersPayload
+/// [Guild Members Chunk]: dichonoia_models::gateway::events::GuildMembersChunk
+#[derive(Debug, Clone)]
+pub struct GuildMembers {
+    /// ID of the guild
+    pub guild_id: GuildId,
+    /// Every matched member, in the order they were received
+    pub members: Vec<Member>,
+    /// Requested IDs that do not belong to a member of the guild
+    pub not_found: Vec<UserId>,
+    /// Presences of the matched members, if they were requested
+    pub presences: Vec<Presence>,
+}
+
+/// Requests waiting for their chunks, keyed by nonce.
+///
+/// Shared between a [`GatewayClient`], which receives the chunks, and its senders, which
+/// send the requests.
+///
+/// [`GatewayClient`]: crate::gateway::GatewayClient
+#[derive(Clone, Default)]
+pub struct MemberRequests {
+    pending: Arc<Mutex<HashMap<String, PendingRequest>>>,
+}
+
+struct PendingRequest {
+    members: Option<GuildMembers>,
+    received: u32,
+    tx: oneshot::Sender<GuildMembers>,
+}
+
+impl MemberRequests {
+    /// Registers a new request, returning its nonce and a receiver for its members.
+    pub fn register(&self) -> (String, oneshot::Receiver<GuildMembers>) {
+        let (tx, rx) = oneshot::channel();
+        let request = PendingRequest {
+            members: None,
+            received: 0,
+            tx,
+        };
+
+        let mut pending = self.lock();
+        let nonce = loop {
+            let nonce = format!("{:016x}", rand::random::<u64>());
+            if !pending.contains_key(&nonce) {
+                break nonce;
+            }
+        };
+        pending.insert(nonce.clone(), request);
+        drop(pending);
+
+        (nonce, rx)
+    }
+
+    /// Forgets the request with `nonce`, such as when it could not be sent.
+    pub fn remove(&self, nonce: &str) {
+        self.lock().remove(nonce);
+    }
+
+    /// Adds `chunk` to its request, and completes the request once every chunk has been
+    /// received.
+    pub fn receive(&self, chunk: &GuildMembersChunk) {
+        let Some(nonce) = &chunk.nonce else {
+            return;
+        };
+
+        let mut pending = self.lock();
+        let Some(request) = pending.get_mut(nonce) else {
+            return;
+        };
+
+        let members = request.members.get_or_insert_with(|| GuildMembers {
+            guild_id: chunk.guild_id,
+            members: Vec::new(),
+            not_found: Vec::new(),
+            presences: Vec::new(),
+        });
+        members.members.extend_from_slice(&chunk.members);
+        members.not_found.extend_from_slice(&chunk.not_found);
+        members.presences.extend_from_slice(&chunk.presences);
+        request.received += 1;
+
+        if request.received >= chunk.chunk_count
+            && let Some(request) = pending.remove(nonce)
+            && let Some(members) = request.members
+        {
+            // The receiver may have been dropped if the request is no longer awaited.
+            let _ = request.tx.send(members);
+        }
+    }
+
+    /// Cancels the request of a [Guild Members Chunk] that could not be deserialized, as
+    /// it cannot be completed without it.
+    ///
+    /// [Guild Members Chunk]: dichonoia_models::gateway::events::GuildMembersChunk
+    pub fn receive_invalid(&self, err: &EventError) {
+        if err.name == "GUILD_MEMBERS_CHUNK"
+            && let Some(nonce) = err.data.get("nonce").and_then(|nonce| nonce.as_str())
+        {
+            self.remove(nonce);
+        }
+    }
+
+    /// Drops every pending request, as their chunks will not be received anymore.
+    pub fn cancel_all(&self) {
+        self.lock().clear();
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, HashMap<String, PendingRequest>> {
+        self.pending.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
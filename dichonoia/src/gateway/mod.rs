@@ -0,0 +1,599 @@
+pub mod builder;
+mod connection;
+#[cfg(feature = "etf")]
+pub mod etf;
+mod heartbeat;
+#[cfg(feature = "zlib-stream")]
+mod inflater;
+mod members;
+pub mod sender;
+pub mod shard;
+
+pub use builder::GatewayClientBuilder;
+pub use connection::{Compression, Encoding};
+pub use members::GuildMembers;
+pub use sender::GatewaySender;
+pub use shard::ShardManager;
+
+use crate::gateway::connection::Connection;
+use crate::gateway::heartbeat::Heartbeater;
+use crate::gateway::members::MemberRequests;
+use dichonoia_models::gateway::events::ready::shard_info::ShardInfo;
+use dichonoia_models::gateway::presence::PresenceUpdatePayload;
+use dichonoia_models::gateway::request_guild_members::MemberFilter;
+use dichonoia_models::gateway::{
+    CloseCode, DispatchPayload, EventError, GatewayEvent, GatewayIntents, GatewayPayload,
+    IdentifyPayload, PayloadError, ResumePayload,
+};
+use dichonoia_models::id::GuildId;
+use futures_util::{Stream, stream};
+use governor::clock::DefaultClock;
+use governor::state::{InMemoryState, NotKeyed};
+use governor::{Quota, RateLimiter};
+use std::num::NonZeroU32;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::mpsc::{self, UnboundedReceiver, UnboundedSender};
+use tokio_tungstenite::tungstenite::Error as TungsteniteError;
+
+pub type Result<T, E = GatewayError> = std::result::Result<T, E>;
+type GatewayRateLimiter = RateLimiter<NotKeyed, InMemoryState, DefaultClock>;
+
+const GATEWAY_URL: &str = "wss://gateway.discord.gg";
+
+/// Number of payloads that can be sent per [`RATELIMIT_PERIOD`].
+const PAYLOADS_PER_PERIOD: u32 = 120;
+
+/// <https://discord.com/developers/docs/events/gateway#rate-limiting>
+const RATELIMIT_PERIOD: Duration = Duration::from_mins(1);
+
+/// Number of payloads that can be sent at once, before the rest of the allowance is
+/// spread over the [`RATELIMIT_PERIOD`].
+const PAYLOADS_BURST: u32 = 5;
+
+/// Close code used when closing a connection that is about to be resumed.
+///
+/// Any code other than `1000` or `1001` keeps the session resumable.
+const RESUMABLE_CLOSE_CODE: u16 = 4000;
+
+/// Delay before retrying a reconnect that failed, or whose connection was lost before
+/// any event was received. It doubles on each consecutive attempt, up to
+/// [`RECONNECT_BACKOFF_MAX`].
+const RECONNECT_BACKOFF_BASE: Duration = Duration::from_secs(1);
+
+/// Upper bound of the delay between two consecutive reconnect attempts.
+const RECONNECT_BACKOFF_MAX: Duration = Duration::from_mins(1);
+
+/// Range (in milliseconds) of the random delay before identifying again after a
+/// non-resumable [Invalid Session].
+///
+/// [Invalid Session]: GatewayPayload::InvalidSession
+const INVALID_SESSION_DELAY: std::ops::RangeInclusive<u64> = 1000..=5000;
+
+#[derive(Debug, thiserror::Error)]
+pub enum GatewayError {
+    #[error("Encountered websocket error: {0}")]
+    Websocket(TungsteniteError),
+    #[error("Encountered json error: {0}")]
+    Json(serde_json::Error),
+    /// A dispatched event could not be deserialized. The client can keep being used, as
+    /// the event was otherwise handled.
+    #[error("{0}")]
+    Event(EventError),
+    #[cfg(feature = "etf")]
+    #[error("Encountered etf error: {0}")]
+    Etf(etf::EtfError),
+    #[cfg(feature = "zlib-stream")]
+    #[error("Encountered decompression error: {0}")]
+    Decompress(flate2::DecompressError),
+    /// The rate limit was hit, or no permit became available before the deadline.
+    #[error("Websocket ratelimit hit")]
+    Ratelimit,
+    /// The gateway closed the connection with a close code that does not allow
+    /// reconnecting.
+    #[error("Gateway closed the connection with {code}: {reason}")]
+    Closed { code: CloseCode, reason: String },
+    /// The connection was closed, or another payload was received, before the [Hello]
+    /// payload that must start every connection.
+    ///
+    /// [Hello]: GatewayPayload::Hello
+    #[error("Gateway did not start the connection with Hello")]
+    MissingHello,
+    /// The session start limit does not allow starting every shard, even once it resets.
+    #[error("Session start limit of {total} is lower than the {required} shards to start")]
+    SessionStartLimit { required: u32, total: u32 },
+    #[error("Gateway client was dropped")]
+    ClientDropped,
+    /// The session a request was sent on was invalidated, its response could not be
+    /// deserialized, or the client was dropped before its response was received.
+    #[error("Request was cancelled before its response was received")]
+    RequestCancelled,
+}
+
+impl From<TungsteniteError> for GatewayError {
+    fn from(value: TungsteniteError) -> Self {
+        Self::Websocket(value)
+    }
+}
+
+impl From<serde_json::Error> for GatewayError {
+    fn from(value: serde_json::Error) -> Self {
+        Self::Json(value)
+    }
+}
+
+impl<E> From<PayloadError<E>> for GatewayError
+where
+    Self: From<E>,
+{
+    fn from(value: PayloadError<E>) -> Self {
+        match value {
+            PayloadError::Invalid(err) => err.into(),
+            PayloadError::Event(err) => Self::Event(err),
+        }
+    }
+}
+
+#[cfg(feature = "etf")]
+impl From<etf::EtfError> for GatewayError {
+    fn from(value: etf::EtfError) -> Self {
+        Self::Etf(value)
+    }
+}
+
+#[cfg(feature = "zlib-stream")]
+impl From<flate2::DecompressError> for GatewayError {
+    fn from(value: flate2::DecompressError) -> Self {
+        Self::Decompress(value)
+    }
+}
+
+pub struct GatewayClient {
+    connection: Connection,
+    heartbeater: Heartbeater,
+    /// URL to connect to when identifying.
+    url: String,
+    identify: IdentifyPayload,
+    encoding: Encoding,
+    compression: Compression,
+    session: Option<Session>,
+    sequence: Option<i64>,
+    /// Reconnect attempts since an event was last received, used for backoff.
+    reconnect_attempts: u32,
+    rate_limiter: Arc<GatewayRateLimiter>,
+    commands_tx: UnboundedSender<GatewayPayload>,
+    commands_rx: UnboundedReceiver<GatewayPayload>,
+    member_requests: MemberRequests,
+}
+
+/// State required to resume a session after a reconnect.
+struct Session {
+    id: String,
+    resume_gateway_url: String,
+}
+
+impl GatewayClient {
+    /// Connects to the gateway with the default options, see
+    /// [`GatewayClient::builder`] to configure them.
+    ///
+    /// # Errors
+    /// ...
+    pub fn connect(token: &str, intents: GatewayIntents) -> impl Future<Output = Result<Self>> {
+        Self::builder(token, intents).connect()
+    }
+
+    /// Connects to the gateway as the given `shard`.
+    ///
+    /// See [`ShardManager`] to run all shards of an app at once.
+    ///
+    /// # Errors
+    /// ...
+    pub fn connect_sharded(
+        token: &str,
+        intents: GatewayIntents,
+        shard: ShardInfo,
+    ) -> impl Future<Output = Result<Self>> {
+        Self::builder(token, intents).shard(shard).connect()
+    }
+
+    /// Creates a builder to configure the connection options before connecting.
+    pub fn builder(token: &str, intents: GatewayIntents) -> GatewayClientBuilder {
+        GatewayClientBuilder::new(token, intents)
+    }
+
+    async fn connect_with(
+        url: String,
+        identify: IdentifyPayload,
+        encoding: Encoding,
+        compression: Compression,
+    ) -> Result<Self> {
+        let (connection, heartbeater) = Self::open(&url, encoding, compression).await?;
+
+        let quota = payloads_quota(heartbeater.interval());
+        let rate_limiter = Arc::new(RateLimiter::direct(quota));
+        let (commands_tx, commands_rx) = mpsc::unbounded_channel();
+
+        let mut client = Self {
+            connection,
+            heartbeater,
+            url,
+            identify,
+            encoding,
+            compression,
+            session: None,
+            sequence: None,
+            reconnect_attempts: 0,
+            rate_limiter,
+            commands_tx,
+            commands_rx,
+            member_requests: MemberRequests::default(),
+        };
+        client.identify().await?;
+
+        Ok(client)
+    }
+
+    /// The shard this client is connected as, if any.
+    #[must_use]
+    pub const fn shard(&self) -> Option<ShardInfo> {
+        self.identify.shard
+    }
+
+    /// Creates a handle for sending payloads through this client from other tasks.
+    ///
+    /// The client itself remains the receiving half, and writes the payloads queued by
+    /// its senders while [reading].
+    ///
+    /// [reading]: GatewayClient::read_payload
+    #[must_use]
+    pub fn sender(&self) -> GatewaySender {
+        GatewaySender::new(
+            self.commands_tx.clone(),
+            Arc::clone(&self.rate_limiter),
+            self.member_requests.clone(),
+        )
+    }
+
+    /// Opens a new connection to `url` and waits for its [Hello] payload.
+    ///
+    /// [Hello]: GatewayPayload::Hello
+    async fn open(
+        url: &str,
+        encoding: Encoding,
+        compression: Compression,
+    ) -> Result<(Connection, Heartbeater)> {
+        let (connection, hello_payload) = Connection::open(url, encoding, compression).await?;
+        let interval = Duration::from_millis(hello_payload.heartbeat_interval);
+
+        Ok((connection, Heartbeater::new(interval)))
+    }
+
+    async fn identify(&mut self) -> Result<()> {
+        self.connection
+            .write(GatewayPayload::Identify(self.identify.clone()))
+            .await
+    }
+
+    /// Closes the current connection and opens a new one, resuming the session if there
+    /// is one to resume and identifying from scratch otherwise.
+    ///
+    /// The first attempt is immediate, but consecutive attempts made without receiving
+    /// any event in between are delayed by an exponential backoff, so that an outage is
+    /// not met with a tight reconnect loop. Failed attempts are retried until one
+    /// succeeds, unless the gateway closes the new connection with a [`CloseCode`] that
+    /// does not allow reconnecting.
+    async fn reconnect(&mut self) -> Result<()> {
+        self.connection
+            .close(RESUMABLE_CLOSE_CODE, "reconnecting")
+            .await;
+
+        loop {
+            if let Some(delay) = reconnect_backoff(self.reconnect_attempts) {
+                tokio::time::sleep(delay).await;
+            }
+            self.reconnect_attempts = self.reconnect_attempts.saturating_add(1);
+
+            match self.reopen().await {
+                Ok(()) => return Ok(()),
+                Err(GatewayError::Closed { code, reason }) if !code.can_reconnect() => {
+                    return Err(GatewayError::Closed { code, reason });
+                }
+                Err(GatewayError::Closed {
+                    code: CloseCode::InvalidSeq | CloseCode::SessionTimedOut,
+                    ..
+                }) => self.invalidate_session(),
+                Err(_) => {}
+            }
+        }
+    }
+
+    /// Opens a new connection, then resumes the session or identifies.
+    async fn reopen(&mut self) -> Result<()> {
+        let (Some(session), Some(seq)) = (&self.session, self.sequence) else {
+            (self.connection, self.heartbeater) =
+                Self::open(&self.url, self.encoding, self.compression).await?;
+            return self.identify().await;
+        };
+
+        (self.connection, self.heartbeater) =
+            Self::open(&session.resume_gateway_url, self.encoding, self.compression).await?;
+        let resume = ResumePayload {
+            token: self.identify.token.clone(),
+            session_id: session.id.clone(),
+            seq,
+        };
+
+        self.connection.write(GatewayPayload::Resume(resume)).await
+    }
+
+    /// Discards the current session, so that the next (re)connection identifies anew.
+    fn invalidate_session(&mut self) {
+        self.session = None;
+        self.sequence = None;
+        self.member_requests.cancel_all();
+    }
+
+    /// Reads the next payload, heartbeating in the background while waiting for it.
+    ///
+    /// Heartbeats and payloads queued by [senders] are only sent while this is being
+    /// awaited, so the client must be polled continuously to keep the connection alive.
+    ///
+    /// The session lifecycle is handled transparently: the client reconnects and
+    /// resumes when the connection drops, is zombied, or when Discord asks for it via
+    /// [Reconnect] or a resumable [Invalid Session], and identifies again when the
+    /// session cannot be resumed. [Reconnect] and [Invalid Session] payloads are still
+    /// returned once they have been handled.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`GatewayError::Closed`] if the gateway closed the connection with a
+    /// [`CloseCode`] that does not allow reconnecting, including while reconnecting.
+    ///
+    /// Returns [`GatewayError::Event`] if a dispatched event cannot be deserialized. Events
+    /// that are not modeled are returned as [`GatewayEvent::Unknown`] instead.
+    ///
+    /// Returns an error if a payload cannot be parsed or cannot be written. Failing to
+    /// re-establish the connection is retried with a backoff instead.
+    ///
+    /// [senders]: GatewayClient::sender
+    /// [Reconnect]: GatewayPayload::Reconnect
+    /// [Invalid Session]: GatewayPayload::InvalidSession
+    pub async fn read_payload(&mut self) -> Result<GatewayPayload> {
+        loop {
+            tokio::select! {
+                () = self.heartbeater.tick() => {
+                    if self.heartbeater.is_zombied() {
+                        self.reconnect().await?;
+                    } else {
+                        self.heartbeat().await?;
+                    }
+                }
+                Some(payload) = self.commands_rx.recv() => {
+                    self.connection.write(payload).await?;
+                }
+                payload = self.connection.read() => {
+                    let payload = match payload {
+                        Ok(Some(payload)) => payload,
+                        Ok(None) => {
+                            self.reconnect().await?;
+                            continue;
+                        }
+                        Err(GatewayError::Closed { code, .. }) if code.can_reconnect() => {
+                            if matches!(code, CloseCode::InvalidSeq | CloseCode::SessionTimedOut) {
+                                self.invalidate_session();
+                            }
+                            self.reconnect().await?;
+                            continue;
+                        }
+                        Err(GatewayError::Event(err)) => {
+                            // The event is still received, so it must be acknowledged.
+                            self.sequence = Some(err.sequence);
+                            self.member_requests.receive_invalid(&err);
+                            return Err(GatewayError::Event(err));
+                        }
+                        Err(err) => return Err(err),
+                    };
+
+                    match &payload {
+                        GatewayPayload::Dispatch(dispatch) => {
+                            self.sequence = Some(dispatch.sequence);
+                            self.reconnect_attempts = 0;
+
+                            match &dispatch.data {
+                                Some(GatewayEvent::Ready(ready)) => {
+                                    self.session = Some(Session {
+                                        id: ready.session_id.clone(),
+                                        resume_gateway_url: ready.resume_gateway_url.clone(),
+                                    });
+                                }
+                                Some(GatewayEvent::GuildMembersChunk(chunk)) => {
+                                    self.member_requests.receive(chunk);
+                                }
+                                _ => {}
+                            }
+                        }
+                        GatewayPayload::Heartbeat(_) => self.heartbeat().await?,
+                        GatewayPayload::HeartBeatACK => self.heartbeater.acknowledged(),
+                        GatewayPayload::Reconnect | GatewayPayload::InvalidSession(true) => {
+                            self.reconnect().await?;
+                        }
+                        GatewayPayload::InvalidSession(false) => {
+                            self.invalidate_session();
+
+                            // Discord requires waiting a random amount of time between 1
+                            // and 5 seconds before identifying again.
+                            let delay = rand::random_range(INVALID_SESSION_DELAY);
+                            tokio::time::sleep(Duration::from_millis(delay)).await;
+
+                            self.identify().await?;
+                        }
+                        _ => {}
+                    }
+
+                    return Ok(payload);
+                }
+            }
+        }
+    }
+
+    /// Reads the next dispatched event, skipping every control payload.
+    ///
+    /// # Errors
+    ///
+    /// See [`GatewayClient::read_payload`].
+    pub async fn next_event(&mut self) -> Result<GatewayEvent> {
+        loop {
+            if let GatewayPayload::Dispatch(DispatchPayload {
+                data: Some(event), ..
+            }) = self.read_payload().await?
+            {
+                return Ok(event);
+            }
+        }
+    }
+
+    /// Stream of dispatched events, see [`GatewayClient::next_event`].
+    ///
+    /// The stream ends after yielding a [`GatewayError::Closed`] error, as the client
+    /// cannot reconnect anymore.
+    pub fn events(&mut self) -> impl Stream<Item = Result<GatewayEvent>> + Unpin + '_ {
+        Box::pin(stream::unfold(Some(self), |client| async move {
+            let client = client?;
+
+            let event = client.next_event().await;
+            let client = match event {
+                Err(GatewayError::Closed { .. }) => None,
+                _ => Some(client),
+            };
+
+            Some((event, client))
+        }))
+    }
+
+    async fn heartbeat(&mut self) -> Result<()> {
+        self.connection
+            .write(GatewayPayload::Heartbeat(self.sequence))
+            .await?;
+        self.heartbeater.sent();
+
+        Ok(())
+    }
+
+    /// # Errors
+    ///
+    /// Returns [`GatewayError::Ratelimit`] if the rate limit was hit. See
+    /// [`GatewayClient::write_payload_waiting`] to wait for it instead.
+    pub async fn write_payload(&mut self, payload: GatewayPayload) -> Result<()> {
+        if self.rate_limiter.check().is_ok() {
+            self.connection.write(payload).await
+        } else {
+            Err(GatewayError::Ratelimit)
+        }
+    }
+
+    /// Updates the presence of the app.
+    ///
+    /// The presence is also sent as the initial presence whenever the client has to
+    /// identify again.
+    ///
+    /// # Errors
+    ///
+    /// See [`GatewayClient::write_payload`].
+    pub async fn update_presence(&mut self, presence: PresenceUpdatePayload) -> Result<()> {
+        self.write_payload(GatewayPayload::PresenceUpdate(presence.clone()))
+            .await?;
+        self.identify.presence = Some(presence);
+
+        Ok(())
+    }
+
+    /// Requests the members of a guild, and waits for every [Guild Members Chunk] of the
+    /// response.
+    ///
+    /// The returned future does not borrow the client, which must keep being polled for
+    /// the request to be sent and its chunks to be received. The chunks are still
+    /// returned as events.
+    ///
+    /// # Errors
+    ///
+    /// See [`GatewaySender::request_members`].
+    ///
+    /// [Guild Members Chunk]: dichonoia_models::gateway::events::GuildMembersChunk
+    pub fn request_members(
+        &self,
+        guild_id: GuildId,
+        filter: MemberFilter,
+        presences: bool,
+    ) -> impl Future<Output = Result<GuildMembers>> + use<> {
+        let sender = self.sender();
+        async move { sender.request_members(guild_id, filter, presences).await }
+    }
+
+    /// Writes `payload`, waiting until the rate limit allows it instead of failing.
+    ///
+    /// No heartbeats are sent while waiting, so `deadline` should be well within the
+    /// heartbeat interval.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`GatewayError::Ratelimit`] if the rate limit still does not allow sending
+    /// `payload` at `deadline`.
+    pub async fn write_payload_waiting(
+        &mut self,
+        payload: GatewayPayload,
+        deadline: Option<Instant>,
+    ) -> Result<()> {
+        wait_for_permit(&self.rate_limiter, deadline).await?;
+        self.connection.write(payload).await
+    }
+}
+
+impl Drop for GatewayClient {
+    fn drop(&mut self) {
+        // Senders share the pending requests, which would otherwise never complete.
+        self.member_requests.cancel_all();
+    }
+}
+
+/// Quota for payloads sent by the app, which excludes the share of
+/// [`PAYLOADS_PER_PERIOD`] reserved for heartbeats, so that they can never be starved.
+///
+/// The quota allows a burst of [`PAYLOADS_BURST`] payloads, then refills one permit at
+/// a time. Any [`RATELIMIT_PERIOD`] can see the whole burst plus every permit refilled
+/// during it, so the refill rate only covers what the burst leaves of the allowance.
+fn payloads_quota(heartbeat_interval: Duration) -> Quota {
+    let interval = heartbeat_interval.as_millis().max(1);
+    // Heartbeats sent on each interval, plus one for a heartbeat requested by Discord.
+    let reserved = RATELIMIT_PERIOD.as_millis().div_ceil(interval) + 1;
+
+    let allowed = u128::from(PAYLOADS_PER_PERIOD).saturating_sub(reserved);
+    let allowed = u32::try_from(allowed).unwrap_or(0);
+
+    let burst = NonZeroU32::new(PAYLOADS_BURST.min(allowed / 2)).unwrap_or(NonZeroU32::MIN);
+    let refilled = NonZeroU32::new(allowed.saturating_sub(burst.get())).unwrap_or(NonZeroU32::MIN);
+
+    Quota::per_minute(refilled).allow_burst(burst)
+}
+
+/// Delay before the next reconnect attempt, or `None` for the first one.
+fn reconnect_backoff(attempts: u32) -> Option<Duration> {
+    let exponent = attempts.checked_sub(1)?;
+    let delay = RECONNECT_BACKOFF_BASE.saturating_mul(2_u32.saturating_pow(exponent));
+
+    Some(delay.min(RECONNECT_BACKOFF_MAX))
+}
+
+/// Waits until `rate_limiter` allows sending a payload, or until `deadline`.
+async fn wait_for_permit(
+    rate_limiter: &GatewayRateLimiter,
+    deadline: Option<Instant>,
+) -> Result<()> {
+    if let Some(deadline) = deadline {
+        tokio::time::timeout_at(deadline.into(), rate_limiter.until_ready())
+            .await
+            .map_err(|_| GatewayError::Ratelimit)
+    } else {
+        rate_limiter.until_ready().await;
+        Ok(())
+    }
+}
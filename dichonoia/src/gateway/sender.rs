@@ -0,0 +1,128 @@
+use crate::gateway::members::{GuildMembers, MemberRequests};
+use crate::gateway::{GatewayError, GatewayRateLimiter, Result, wait_for_permit};
+use dichonoia_models::gateway::GatewayPayload;
+use dichonoia_models::gateway::request_guild_members::{MemberFilter, RequestGuildMembersPayload};
+use dichonoia_models::id::GuildId;
+use std::sync::Arc;
+use std::time::Instant;
+use tokio::sync::mpsc::UnboundedSender;
+
+/// Cloneable handle for sending payloads through a [`GatewayClient`] from other tasks.
+///
+/// Payloads are queued and written by the [`GatewayClient`] while it is reading, so the
+/// client must be polled for them to be sent. They share the rate limit of
+/// [`GatewayClient::write_payload`].
+///
+/// [`GatewayClient`]: crate::gateway::GatewayClient
+/// [`GatewayClient::write_payload`]: crate::gateway::GatewayClient::write_payload
+#[derive(Clone)]
+pub struct GatewaySender {
+    tx: UnboundedSender<GatewayPayload>,
+    rate_limiter: Arc<GatewayRateLimiter>,
+    member_requests: MemberRequests,
+}
+
+impl GatewaySender {
+    pub(super) const fn new(
+        tx: UnboundedSender<GatewayPayload>,
+        rate_limiter: Arc<GatewayRateLimiter>,
+        member_requests: MemberRequests,
+    ) -> Self {
+        Self {
+            tx,
+            rate_limiter,
+            member_requests,
+        }
+    }
+
+    /// Queues `payload` to be sent by the [`GatewayClient`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`GatewayError::Ratelimit`] if the rate limit was hit, and
+    /// [`GatewayError::ClientDropped`] if the [`GatewayClient`] no longer exists. See
+    /// [`GatewaySender::send_waiting`] to wait for the rate limit instead.
+    ///
+    /// [`GatewayClient`]: crate::gateway::GatewayClient
+    pub fn send(&self, payload: GatewayPayload) -> Result<()> {
+        if self.tx.is_closed() {
+            return Err(GatewayError::ClientDropped);
+        }
+
+        self.rate_limiter
+            .check()
+            .map_err(|_| GatewayError::Ratelimit)?;
+        self.tx
+            .send(payload)
+            .map_err(|_| GatewayError::ClientDropped)
+    }
+
+    /// Queues `payload` to be sent by the [`GatewayClient`], waiting until the rate limit
+    /// allows it instead of failing.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`GatewayError::Ratelimit`] if the rate limit still does not allow sending
+    /// `payload` at `deadline`, and [`GatewayError::ClientDropped`] if the
+    /// [`GatewayClient`] no longer exists.
+    ///
+    /// [`GatewayClient`]: crate::gateway::GatewayClient
+    pub async fn send_waiting(
+        &self,
+        payload: GatewayPayload,
+        deadline: Option<Instant>,
+    ) -> Result<()> {
+        if self.tx.is_closed() {
+            return Err(GatewayError::ClientDropped);
+        }
+
+        wait_for_permit(&self.rate_limiter, deadline).await?;
+        self.tx
+            .send(payload)
+            .map_err(|_| GatewayError::ClientDropped)
+    }
+
+    /// Requests the members of a guild, and waits for every [Guild Members Chunk] of the
+    /// response, waiting until the rate limit allows sending the request.
+    ///
+    /// A nonce is generated to match the chunks to the request.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`GatewayError::ClientDropped`] if the [`GatewayClient`] no longer exists,
+    /// and [`GatewayError::RequestCancelled`] if the session was invalidated, one of the
+    /// chunks could not be deserialized, or the [`GatewayClient`] was dropped before
+    /// every chunk was received.
+    ///
+    /// [Guild Members Chunk]: dichonoia_models::gateway::events::GuildMembersChunk
+    /// [`GatewayClient`]: crate::gateway::GatewayClient
+    pub async fn request_members(
+        &self,
+        guild_id: GuildId,
+        filter: MemberFilter,
+        presences: bool,
+    ) -> Result<GuildMembers> {
+        if self.tx.is_closed() {
+            return Err(GatewayError::ClientDropped);
+        }
+
+        wait_for_permit(&self.rate_limiter, None).await?;
+
+        // Registered only once nothing is awaited before sending anymore, so that
+        // dropping this future cannot leave the request pending forever.
+        let (nonce, rx) = self.member_requests.register();
+        let payload = GatewayPayload::RequestGuildMembers(RequestGuildMembersPayload {
+            guild_id,
+            filter,
+            presences,
+            nonce: Some(nonce.clone()),
+        });
+
+        if self.tx.send(payload).is_err() {
+            self.member_requests.remove(&nonce);
+            return Err(GatewayError::ClientDropped);
+        }
+
+        rx.await.map_err(|_| GatewayError::RequestCancelled)
+    }
+}
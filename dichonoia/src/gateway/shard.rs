@@ -0,0 +1,193 @@
+use crate::gateway::{GatewayClient, GatewayClientBuilder, GatewayError, GatewaySender, Result};
+use dichonoia_models::gateway::events::ready::shard_info::ShardInfo;
+use dichonoia_models::gateway::{GatewayBot, GatewayEvent, GatewayIntents, SessionStartLimit};
+use futures_util::future::try_join_all;
+use futures_util::{Stream, stream};
+use std::num::NonZeroU16;
+use std::ops::Range;
+use std::time::Duration;
+use tokio::sync::mpsc::{self, UnboundedReceiver, UnboundedSender};
+use tokio::task::JoinSet;
+use tokio::time::Instant;
+
+/// Window in which at most `max_concurrency` shards may identify.
+const IDENTIFY_WINDOW: Duration = Duration::from_secs(5);
+
+/// Window after which the session start limit resets again, once it has reset.
+const SESSION_START_LIMIT_WINDOW: Duration = Duration::from_hours(24);
+
+/// Event received by a shard, tagged with that shard.
+type ShardEvent = (ShardInfo, Result<GatewayEvent>);
+
+/// Runs every shard of an app, and merges their payloads into a single stream.
+///
+/// Shards are started by [rate limit key] bucket, **in order**: shards `0` to
+/// `max_concurrency - 1` identify concurrently, then shards `max_concurrency` to
+/// `2 * max_concurrency - 1` after the identify window has passed, and so on.
+///
+/// Each shard is read in a task of its own as soon as its bucket has connected, so that
+/// it keeps heartbeating while the next buckets are started. Its events are buffered
+/// until they are taken from [`ShardManager::events`], and the tasks are aborted when
+/// the manager or its event stream is dropped.
+///
+/// [rate limit key]: dichonoia_models::gateway::events::ready::shard_info::ShardInfo#max-concurrency
+pub struct ShardManager {
+    senders: Vec<GatewaySender>,
+    events: UnboundedReceiver<ShardEvent>,
+    readers: JoinSet<()>,
+}
+
+impl ShardManager {
+    /// Starts the number of shards recommended by [Get Gateway Bot].
+    ///
+    /// If there are not enough session starts remaining to start every shard, the
+    /// buckets that do not fit wait until the session start limit resets.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`GatewayError::SessionStartLimit`] if the session start limit is lower
+    /// than the number of shards, and an error if any of the shards fails to connect.
+    ///
+    /// [Get Gateway Bot]: crate::http::HttpClient::get_gateway_bot
+    pub fn start(
+        token: &str,
+        intents: GatewayIntents,
+        gateway_bot: &GatewayBot,
+    ) -> impl Future<Output = Result<Self>> {
+        let builder = GatewayClient::builder(token, intents).url(gateway_bot.url.clone());
+        Self::start_with(builder, gateway_bot)
+    }
+
+    /// Starts the number of shards recommended by [Get Gateway Bot], connecting each of
+    /// them with the options of `builder`.
+    ///
+    /// The shard set on `builder` is overridden for each shard.
+    ///
+    /// # Errors
+    ///
+    /// See [`ShardManager::start`].
+    ///
+    /// [Get Gateway Bot]: crate::http::HttpClient::get_gateway_bot
+    pub async fn start_with(
+        builder: GatewayClientBuilder,
+        gateway_bot: &GatewayBot,
+    ) -> Result<Self> {
+        let num = gateway_bot.shards;
+        let limit = &gateway_bot.session_start_limit;
+
+        let required = u32::from(num.get());
+        if limit.total < required {
+            return Err(GatewayError::SessionStartLimit {
+                required,
+                total: limit.total,
+            });
+        }
+
+        let mut session_starts = SessionStarts::new(limit);
+        let (events_tx, events) = mpsc::unbounded_channel();
+        let mut senders = Vec::with_capacity(usize::from(num.get()));
+        let mut readers = JoinSet::new();
+
+        for (n, bucket) in buckets(num, limit.max_concurrency).enumerate() {
+            if n != 0 {
+                tokio::time::sleep(IDENTIFY_WINDOW).await;
+            }
+            session_starts
+                .take(u32::from(bucket.end - bucket.start))
+                .await;
+
+            let connecting =
+                bucket.map(|id| builder.clone().shard(ShardInfo { id, num }).connect());
+            for client in try_join_all(connecting).await? {
+                senders.push(client.sender());
+                readers.spawn(read_events(client, events_tx.clone()));
+            }
+        }
+
+        Ok(Self {
+            senders,
+            events,
+            readers,
+        })
+    }
+
+    /// Creates a handle for sending payloads through the shard with the given ID.
+    #[must_use]
+    pub fn sender(&self, shard_id: u16) -> Option<GatewaySender> {
+        self.senders.get(usize::from(shard_id)).cloned()
+    }
+
+    /// Merges the events of every shard into a single stream, tagged with the shard
+    /// they were received on.
+    ///
+    /// A shard stops yielding events once it returns a [`GatewayError::Closed`] error,
+    /// as it cannot reconnect anymore.
+    pub fn events(self) -> impl Stream<Item = ShardEvent> + Unpin {
+        let state = (self.events, self.readers);
+
+        Box::pin(stream::unfold(state, |(mut events, readers)| async move {
+            let event = events.recv().await?;
+            Some((event, (events, readers)))
+        }))
+    }
+}
+
+/// Reads the events of `client` until it is closed, or the manager is dropped.
+async fn read_events(mut client: GatewayClient, events: UnboundedSender<ShardEvent>) {
+    let Some(shard) = client.shard() else {
+        return;
+    };
+
+    loop {
+        let event = client.next_event().await;
+        let closed = matches!(event, Err(GatewayError::Closed { .. }));
+
+        if events.send((shard, event)).is_err() || closed {
+            return;
+        }
+    }
+}
+
+/// Session starts left until the [session start limit] resets.
+///
+/// [session start limit]: SessionStartLimit
+struct SessionStarts {
+    remaining: u32,
+    total: u32,
+    reset_at: Instant,
+}
+
+impl SessionStarts {
+    fn new(limit: &SessionStartLimit) -> Self {
+        Self {
+            remaining: limit.remaining,
+            total: limit.total,
+            reset_at: Instant::now() + Duration::from_millis(limit.reset_after),
+        }
+    }
+
+    /// Takes `count` session starts, waiting for the limit to reset first if there are
+    /// not enough of them left.
+    async fn take(&mut self, count: u32) {
+        if self.remaining < count {
+            tokio::time::sleep_until(self.reset_at).await;
+            self.remaining = self.total;
+            self.reset_at = Instant::now() + SESSION_START_LIMIT_WINDOW;
+        }
+
+        self.remaining = self.remaining.saturating_sub(count);
+    }
+}
+
+/// Splits shard IDs `0..num` into consecutive buckets of `max_concurrency` shards.
+///
+/// Shards in the same bucket all have a different rate limit key
+/// (`shard_id % max_concurrency`), so they can identify concurrently.
+fn buckets(num: NonZeroU16, max_concurrency: NonZeroU16) -> impl Iterator<Item = Range<u16>> {
+    let num = num.get();
+    let max_concurrency = max_concurrency.get();
+
+    (0..num)
+        .step_by(usize::from(max_concurrency))
+        .map(move |start| start..num.min(start.saturating_add(max_concurrency)))
+}
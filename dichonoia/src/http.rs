@@ -9,6 +9,7 @@ type Result<T, E = HttpError> = std::result::Result<T, E>;
 
 #[derive(Debug)]
 pub struct HttpClient {
+    #[expect(dead_code)]
     token: String,
     http: Client,
     rate_limiter: RateLimiter,
@@ -78,6 +79,7 @@ impl HttpClient {
         resp.json().await.map_err(HttpError::from)
     }
 
+    #[expect(dead_code)]
     async fn get_query<Q: Serialize + ?Sized + Send + Sync, B: DeserializeOwned>(
         &self,
         path: &str,
@@ -95,6 +97,7 @@ impl HttpClient {
         resp.json().await.map_err(HttpError::from)
     }
 
+    #[expect(dead_code)]
     async fn post<Req: Serialize + ?Sized + Send + Sync, Resp: DeserializeOwned>(
         &self,
         path: &str,
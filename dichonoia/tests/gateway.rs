@@ -0,0 +1,381 @@
+//! Runs the gateway client against a local WebSocket server standing in for Discord.
+
+use std::time::Duration;
+
+use dichonoia::gateway::{GatewayClient, GatewayError};
+use dichonoia_models::gateway::{CloseCode, GatewayIntents, GatewayPayload};
+use futures_util::{SinkExt, StreamExt};
+use serde_json::{Value, json};
+use tokio::net::{TcpListener, TcpStream};
+use tokio_tungstenite::WebSocketStream;
+use tokio_tungstenite::tungstenite::Message;
+use tokio_tungstenite::tungstenite::protocol::CloseFrame;
+
+/// Heartbeat interval long enough for no heartbeat to be due during a test.
+const LONG_INTERVAL: u64 = 60_000;
+
+const SESSION_ID: &str = "session";
+
+/// Local stand-in for the gateway, accepting any number of connections.
+struct Server {
+    listener: TcpListener,
+    url: String,
+}
+
+impl Server {
+    async fn bind() -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").await.expect("bind");
+        let addr = listener.local_addr().expect("local address");
+
+        Self {
+            listener,
+            url: format!("ws://{addr}"),
+        }
+    }
+
+    async fn accept(&self) -> Peer {
+        let (stream, _) = self.listener.accept().await.expect("accept");
+        let stream = tokio_tungstenite::accept_async(stream)
+            .await
+            .expect("handshake");
+
+        Peer { stream }
+    }
+
+    /// Accepts a connection and starts it with Hello.
+    async fn accept_hello(&self, heartbeat_interval: u64) -> Peer {
+        let mut peer = self.accept().await;
+        peer.send(json!({ "op": 10, "d": { "heartbeat_interval": heartbeat_interval } }))
+            .await;
+        peer
+    }
+
+    /// Connects a client that has identified, along with the server side of its
+    /// connection.
+    async fn connect(&self, heartbeat_interval: u64) -> (GatewayClient, Peer) {
+        let connecting = GatewayClient::builder("token", GatewayIntents::empty())
+            .url(self.url.clone())
+            .connect();
+        let (client, mut peer) = tokio::join!(connecting, self.accept_hello(heartbeat_interval));
+        let client = client.expect("connect");
+
+        assert_eq!(peer.recv().await["op"], 2, "identify");
+        (client, peer)
+    }
+
+    /// Connects a client that has received Ready, with session [`SESSION_ID`] and
+    /// sequence `1`.
+    async fn connect_ready(&self) -> (GatewayClient, Peer) {
+        let (mut client, mut peer) = self.connect(LONG_INTERVAL).await;
+        peer.send(ready(&self.url)).await;
+
+        let payload = client.read_payload().await.expect("ready");
+        assert!(matches!(payload, GatewayPayload::Dispatch(_)));
+        (client, peer)
+    }
+}
+
+/// Server side of a single connection.
+struct Peer {
+    stream: WebSocketStream<TcpStream>,
+}
+
+impl Peer {
+    async fn send(&mut self, payload: Value) {
+        let message = Message::Text(payload.to_string().into());
+        self.stream.send(message).await.expect("send");
+    }
+
+    /// Receives the next payload, including heartbeats.
+    async fn recv_any(&mut self) -> Value {
+        loop {
+            match self.stream.next().await.expect("open").expect("message") {
+                Message::Text(text) => return serde_json::from_str(&text).expect("json"),
+                Message::Close(frame) => panic!("connection closed with {frame:?}"),
+                _ => {}
+            }
+        }
+    }
+
+    /// Receives the next payload that is not a heartbeat.
+    async fn recv(&mut self) -> Value {
+        loop {
+            let payload = self.recv_any().await;
+            if payload["op"] != 1 {
+                return payload;
+            }
+        }
+    }
+
+    async fn close(&mut self, code: u16) {
+        let frame = CloseFrame {
+            code: code.into(),
+            reason: "closing".into(),
+        };
+        self.stream.close(Some(frame)).await.expect("close");
+    }
+}
+
+fn ready(resume_gateway_url: &str) -> Value {
+    json!({
+        "op": 0,
+        "s": 1,
+        "t": "READY",
+        "d": {
+            "v": 10,
+            "user": {
+                "id": "1",
+                "username": "bot",
+                "discriminator": "0",
+                "global_name": null,
+                "avatar": null,
+            },
+            "guilds": [],
+            "session_id": SESSION_ID,
+            "resume_gateway_url": resume_gateway_url,
+            "application": { "id": "1", "flags": 0 },
+        },
+    })
+}
+
+/// Keeps reading payloads until `server` is done.
+async fn drive<T>(client: &mut GatewayClient, server: impl Future<Output = T>) -> T {
+    let reading = async {
+        loop {
+            client.read_payload().await.expect("payload");
+        }
+    };
+
+    tokio::select! {
+        () = reading => unreachable!(),
+        out = server => out,
+    }
+}
+
+fn assert_resume(payload: &Value) {
+    assert_eq!(payload["op"], 6, "resume");
+    assert_eq!(payload["d"]["session_id"], SESSION_ID);
+    assert_eq!(payload["d"]["seq"], 1);
+}
+
+#[tokio::test]
+async fn missed_ack_reconnects() {
+    let server = Server::bind().await;
+    let (mut client, mut peer) = server.connect(100).await;
+
+    let reconnected = drive(&mut client, async {
+        assert_eq!(peer.recv_any().await["op"], 1, "heartbeat");
+
+        // The heartbeat is never acknowledged, so the next one finds it zombied.
+        let mut peer = server.accept_hello(LONG_INTERVAL).await;
+        peer.recv().await
+    })
+    .await;
+
+    assert_eq!(reconnected["op"], 2, "identify without a session");
+}
+
+#[tokio::test]
+async fn acknowledged_heartbeats_keep_the_connection() {
+    let server = Server::bind().await;
+    let (mut client, mut peer) = server.connect(50).await;
+
+    drive(&mut client, async {
+        for _ in 0..3 {
+            assert_eq!(peer.recv_any().await["op"], 1, "heartbeat");
+            peer.send(json!({ "op": 11 })).await;
+        }
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn reconnect_resumes() {
+    let server = Server::bind().await;
+    let (mut client, mut peer) = server.connect_ready().await;
+
+    peer.send(json!({ "op": 7, "d": null })).await;
+    let (payload, mut peer) =
+        tokio::join!(client.read_payload(), server.accept_hello(LONG_INTERVAL));
+
+    assert!(matches!(payload, Ok(GatewayPayload::Reconnect)));
+    assert_resume(&peer.recv().await);
+}
+
+#[tokio::test]
+async fn resumable_invalid_session_resumes() {
+    let server = Server::bind().await;
+    let (mut client, mut peer) = server.connect_ready().await;
+
+    peer.send(json!({ "op": 9, "d": true })).await;
+    let (payload, mut peer) =
+        tokio::join!(client.read_payload(), server.accept_hello(LONG_INTERVAL));
+
+    assert!(matches!(payload, Ok(GatewayPayload::InvalidSession(true))));
+    assert_resume(&peer.recv().await);
+}
+
+#[tokio::test]
+async fn invalid_session_identifies_again() {
+    let server = Server::bind().await;
+    let (mut client, mut peer) = server.connect_ready().await;
+
+    peer.send(json!({ "op": 9, "d": false })).await;
+    let (payload, identify) = tokio::join!(client.read_payload(), peer.recv());
+
+    assert!(matches!(payload, Ok(GatewayPayload::InvalidSession(false))));
+    assert_eq!(identify["op"], 2, "identify on the same connection");
+}
+
+#[tokio::test]
+async fn reconnectable_close_resumes() {
+    let server = Server::bind().await;
+    let (mut client, mut peer) = server.connect_ready().await;
+
+    let resumed = drive(&mut client, async {
+        peer.close(CloseCode::UnknownError.get()).await;
+        server.accept_hello(LONG_INTERVAL).await.recv().await
+    })
+    .await;
+
+    assert_resume(&resumed);
+}
+
+#[tokio::test]
+async fn invalid_seq_close_identifies_again() {
+    let server = Server::bind().await;
+    let (mut client, mut peer) = server.connect_ready().await;
+
+    let identified = drive(&mut client, async {
+        peer.close(CloseCode::InvalidSeq.get()).await;
+        server.accept_hello(LONG_INTERVAL).await.recv().await
+    })
+    .await;
+
+    assert_eq!(identified["op"], 2, "identify");
+}
+
+#[tokio::test]
+async fn fatal_close_is_returned() {
+    let server = Server::bind().await;
+    let (mut client, mut peer) = server.connect(LONG_INTERVAL).await;
+
+    let (payload, ()) = tokio::join!(
+        client.read_payload(),
+        peer.close(CloseCode::AuthenticationFailed.get())
+    );
+
+    assert!(matches!(
+        payload,
+        Err(GatewayError::Closed {
+            code: CloseCode::AuthenticationFailed,
+            ..
+        })
+    ));
+}
+
+#[tokio::test]
+async fn close_before_hello_is_retried() {
+    let server = Server::bind().await;
+    let (mut client, mut peer) = server.connect_ready().await;
+
+    let resumed = drive(&mut client, async {
+        peer.send(json!({ "op": 7, "d": null })).await;
+
+        // The new connection is closed before Hello, which must not end the client.
+        let mut failed = server.accept().await;
+        failed.close(CloseCode::UnknownError.get()).await;
+
+        let retried = server.accept_hello(LONG_INTERVAL);
+        let mut peer = tokio::time::timeout(Duration::from_secs(5), retried)
+            .await
+            .expect("retried after a backoff");
+        peer.recv().await
+    })
+    .await;
+
+    assert_resume(&resumed);
+}
+
+#[tokio::test]
+async fn fatal_close_before_hello_is_returned() {
+    let server = Server::bind().await;
+    let (mut client, mut peer) = server.connect_ready().await;
+
+    peer.send(json!({ "op": 7, "d": null })).await;
+    let (reconnect, ()) = tokio::join!(client.read_payload(), async {
+        let mut failed = server.accept().await;
+        failed.close(CloseCode::AuthenticationFailed.get()).await;
+    });
+
+    assert!(matches!(
+        reconnect,
+        Err(GatewayError::Closed {
+            code: CloseCode::AuthenticationFailed,
+            ..
+        })
+    ));
+}
+
+/// First two messages of a `zlib-stream` connection, as recorded from Discord: Hello with
+/// a 41250 ms interval, then a heartbeat ACK compressed with the same context.
+#[cfg(feature = "zlib-stream")]
+const HELLO_FRAME: [u8; 50] = [
+    0x78, 0x9c, 0xaa, 0x56, 0xca, 0x2f, 0x50, 0xb2, 0x32, 0x34, 0xd0, 0x51, 0x4a, 0x51, 0xb2, 0xaa,
+    0x56, 0xca, 0x48, 0x4d, 0x2c, 0x2a, 0x49, 0x4a, 0x4d, 0x2c, 0x89, 0xcf, 0xcc, 0x2b, 0x49, 0x2d,
+    0x2a, 0x4b, 0xcc, 0x51, 0xb2, 0x32, 0x31, 0x34, 0x32, 0x35, 0xa8, 0xad, 0x05, 0x00, 0x00, 0x00,
+    0xff, 0xff,
+];
+#[cfg(feature = "zlib-stream")]
+const ACK_FRAME: [u8; 17] = [
+    0xaa, 0x86, 0xa8, 0x34, 0x04, 0xab, 0xcc, 0x2b, 0xcd, 0xc9, 0xa9, 0x05, 0x00, 0x00, 0x00, 0xff,
+    0xff,
+];
+
+#[cfg(feature = "zlib-stream")]
+#[tokio::test]
+#[expect(
+    clippy::result_large_err,
+    reason = "the handshake callback type is set by tungstenite"
+)]
+async fn zlib_stream_frames_are_inflated() {
+    use dichonoia::gateway::Compression;
+    use tokio_tungstenite::tungstenite::handshake::server::{ErrorResponse, Request};
+
+    let server = Server::bind().await;
+    let connecting = GatewayClient::builder("token", GatewayIntents::empty())
+        .url(server.url.clone())
+        .compression(Compression::ZlibStream)
+        .connect();
+    let accepting = async {
+        let (stream, _) = server.listener.accept().await.expect("accept");
+        let mut query = None;
+        let stream = tokio_tungstenite::accept_hdr_async(stream, |request: &Request, response| {
+            query = request.uri().query().map(str::to_owned);
+            Ok::<_, ErrorResponse>(response)
+        })
+        .await
+        .expect("handshake");
+
+        // Hello is split in the middle of its `Z_SYNC_FLUSH` suffix, so the client must
+        // wait for the second frame before inflating it.
+        let mut peer = Peer { stream };
+        for frame in HELLO_FRAME.chunks(HELLO_FRAME.len() - 2) {
+            let message = Message::Binary(frame.to_vec().into());
+            peer.stream.send(message).await.expect("send");
+        }
+        (peer, query)
+    };
+
+    let (client, (mut peer, query)) = tokio::join!(connecting, accepting);
+    let mut client = client.expect("connect after an inflated hello");
+    let query = query.expect("query");
+    assert!(query.contains("compress=zlib-stream"), "{query}");
+    assert_eq!(peer.recv().await["op"], 2, "identify");
+
+    // The ACK is only decompressible with the context left over from Hello.
+    let message = Message::Binary(ACK_FRAME.to_vec().into());
+    peer.stream.send(message).await.expect("send");
+    let payload = client.read_payload().await.expect("ack");
+    assert!(matches!(payload, GatewayPayload::HeartBeatACK));
+}